@@ -0,0 +1,175 @@
+use crate::reader::DictReader;
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::Read;
+
+impl<R: Read> DictReader<R> {
+    // column 값을 table로 치환. table에 없는 값은 default로, default가 None이면 원래 값을 유지
+    pub fn map_column(
+        self,
+        column: &str,
+        table: HashMap<String, String>,
+        default: Option<String>,
+    ) -> Result<MapColumnReader<R>, Box<dyn Error>> {
+        if !self.header.iter().any(|f| f == column) {
+            return Err(format!("Column {:?} is not in the header", column).into());
+        }
+        Ok(MapColumnReader {
+            reader: self,
+            column: column.to_string(),
+            table,
+            default,
+        })
+    }
+
+    // pred가 false인 레코드를 건너뛰고, 건너뛴 개수는 dropped_count()로 확인할 수 있음
+    pub fn filter_valid<F>(self, pred: F) -> FilterValidReader<R, F>
+    where
+        F: FnMut(&HashMap<String, String>) -> bool,
+    {
+        FilterValidReader {
+            reader: self,
+            pred,
+            dropped: 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MapColumnReader<R: Read> {
+    pub reader: DictReader<R>,
+    pub column: String,
+    pub table: HashMap<String, String>,
+    pub default: Option<String>,
+}
+
+impl<R: Read> MapColumnReader<R> {
+    pub fn read_record(&mut self) -> Result<Option<HashMap<String, String>>, Box<dyn Error>> {
+        let mut record = match self.reader.read_record()? {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        if let Some(value) = record.get_mut(&self.column) {
+            if let Some(mapped) = self.table.get(value.as_str()).or(self.default.as_ref()) {
+                *value = mapped.clone();
+            }
+        }
+        Ok(Some(record))
+    }
+}
+
+impl<R: Read> Iterator for MapColumnReader<R> {
+    type Item = Result<HashMap<String, String>, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+pub struct FilterValidReader<R, F>
+where
+    R: Read,
+    F: FnMut(&HashMap<String, String>) -> bool,
+{
+    pub reader: DictReader<R>,
+    pub pred: F,
+    dropped: u64,
+}
+
+impl<R, F> FilterValidReader<R, F>
+where
+    R: Read,
+    F: FnMut(&HashMap<String, String>) -> bool,
+{
+    pub fn read_record(&mut self) -> Result<Option<HashMap<String, String>>, Box<dyn Error>> {
+        while let Some(record) = self.reader.read_record()? {
+            if (self.pred)(&record) {
+                return Ok(Some(record));
+            }
+            self.dropped += 1;
+        }
+        Ok(None)
+    }
+
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped
+    }
+}
+
+impl<R, F> Iterator for FilterValidReader<R, F>
+where
+    R: Read,
+    F: FnMut(&HashMap<String, String>) -> bool,
+{
+    type Item = Result<HashMap<String, String>, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    fn countries() -> HashMap<String, String> {
+        let mut table = HashMap::new();
+        table.insert("US".to_string(), "United States".to_string());
+        table.insert("KR".to_string(), "South Korea".to_string());
+        table
+    }
+
+    #[test]
+    fn test_map_column() -> Result<(), Box<dyn Error>> {
+        let data = "id,country\n1,US\n2,XX\n3,KR".to_string();
+        let reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let records = reader
+            .map_column("country", countries(), None)?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records[0].get("country").unwrap(), "United States");
+        assert_eq!(records[1].get("country").unwrap(), "XX");
+        assert_eq!(records[2].get("country").unwrap(), "South Korea");
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_column_default() -> Result<(), Box<dyn Error>> {
+        let data = "id,country\n1,US\n2,XX".to_string();
+        let reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let mut reader = reader.map_column("country", countries(), Some("Unknown".to_string()))?;
+        assert_eq!(
+            reader.read_record()?.unwrap().get("country").unwrap(),
+            "United States"
+        );
+        assert_eq!(
+            reader.read_record()?.unwrap().get("country").unwrap(),
+            "Unknown"
+        );
+        assert!(reader.read_record()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_column_missing_column() -> Result<(), Box<dyn Error>> {
+        let data = "id,country\n1,US".to_string();
+        let reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        assert!(reader.map_column("nation", countries(), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_valid() -> Result<(), Box<dyn Error>> {
+        let data = "id,name\n1,alice\n,bob\n3,carol\n,dave".to_string();
+        let reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let mut filtered = reader.filter_valid(|record| !record["id"].is_empty());
+        let names = filtered
+            .by_ref()
+            .map(|record| record.map(|r| r["name"].clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(names, vec!["alice", "carol"]);
+        assert_eq!(filtered.dropped_count(), 2);
+        Ok(())
+    }
+}
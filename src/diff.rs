@@ -0,0 +1,122 @@
+use crate::reader::DictReader;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::io::Read;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowChange {
+    pub key: String,
+    pub columns: Vec<String>,
+    pub left: HashMap<String, String>,
+    pub right: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CsvDiff {
+    pub only_left: Vec<HashMap<String, String>>,
+    pub only_right: Vec<HashMap<String, String>>,
+    pub changed: Vec<RowChange>,
+}
+
+// key 컬럼 기준으로 두 CSV를 비교. right 쪽을 key -> 레코드로 인덱싱하고 left는 스트리밍으로 읽음
+// 두 헤더가 다르면 공통 컬럼만 비교함
+pub fn diff<R1: Read, R2: Read>(
+    left: &mut DictReader<R1>,
+    right: &mut DictReader<R2>,
+    key: &str,
+) -> Result<CsvDiff, Box<dyn Error>> {
+    if !left.header.iter().any(|f| f == key) || !right.header.iter().any(|f| f == key) {
+        return Err(format!("Key column {:?} is missing from one of the inputs", key).into());
+    }
+    let columns: Vec<String> = left
+        .header
+        .iter()
+        .filter(|f| right.header.contains(f))
+        .cloned()
+        .collect();
+
+    let mut right_records = Vec::new();
+    let mut right_index = HashMap::new();
+    while let Some(record) = right.read_record()? {
+        let value = record[key].clone();
+        if right_index
+            .insert(value.clone(), right_records.len())
+            .is_some()
+        {
+            return Err(format!("Duplicate key {:?} in right input", value).into());
+        }
+        right_records.push(Some(record));
+    }
+
+    let mut result = CsvDiff::default();
+    let mut seen = HashSet::new();
+    while let Some(record) = left.read_record()? {
+        let value = record[key].clone();
+        if !seen.insert(value.clone()) {
+            return Err(format!("Duplicate key {:?} in left input", value).into());
+        }
+        let other = match right_index.get(&value) {
+            Some(&i) => right_records[i].take(),
+            None => None,
+        };
+        match other {
+            Some(other) => {
+                let changed: Vec<String> = columns
+                    .iter()
+                    .filter(|c| record[*c] != other[*c])
+                    .cloned()
+                    .collect();
+                if !changed.is_empty() {
+                    result.changed.push(RowChange {
+                        key: value,
+                        columns: changed,
+                        left: record,
+                        right: other,
+                    });
+                }
+            }
+            None => result.only_left.push(record),
+        }
+    }
+    result.only_right = right_records.into_iter().flatten().collect();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_diff() -> Result<(), Box<dyn Error>> {
+        let left_data = "id,name,age\n1,alice,30\n2,bob,25\n3,carol,40".to_string();
+        let right_data = "id,name,city\n1,alice,seoul\n3,carol b,busan\n4,dave,incheon".to_string();
+        let mut left = DictReader::new(Cursor::new(left_data), ReaderOptions::default())?;
+        let mut right = DictReader::new(Cursor::new(right_data), ReaderOptions::default())?;
+        let result = diff(&mut left, &mut right, "id")?;
+
+        assert_eq!(result.only_left.len(), 1);
+        assert_eq!(result.only_left[0].get("name").unwrap(), "bob");
+        assert_eq!(result.only_right.len(), 1);
+        assert_eq!(result.only_right[0].get("name").unwrap(), "dave");
+        assert_eq!(result.changed.len(), 1);
+        assert_eq!(result.changed[0].key, "3");
+        assert_eq!(result.changed[0].columns, vec!["name"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_missing_key_column() -> Result<(), Box<dyn Error>> {
+        let mut left = DictReader::new(
+            Cursor::new("id,name\n1,a".to_string()),
+            ReaderOptions::default(),
+        )?;
+        let mut right = DictReader::new(
+            Cursor::new("no,name\n1,a".to_string()),
+            ReaderOptions::default(),
+        )?;
+        assert!(diff(&mut left, &mut right, "id").is_err());
+        Ok(())
+    }
+}
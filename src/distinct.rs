@@ -0,0 +1,162 @@
+use crate::reader::DictReader;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+// 컬럼별 서로 다른 값의 개수. threshold 이하이면 정확한 값, 넘으면 HyperLogLog 추정값
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistinctCount {
+    Exact(u64),
+    Approximate(u64),
+}
+
+impl DistinctCount {
+    pub fn value(&self) -> u64 {
+        match self {
+            DistinctCount::Exact(n) | DistinctCount::Approximate(n) => *n,
+        }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        matches!(self, DistinctCount::Exact(_))
+    }
+}
+
+// 정확하게 셀 서로 다른 값의 기본 최대 개수
+pub const DEFAULT_EXACT_THRESHOLD: usize = 10_000;
+
+// HyperLogLog 레지스터 수 = 2^HLL_PRECISION (16384개, 컬럼당 16KB).
+// 추정값의 상대 표준 오차는 1.04 / sqrt(16384) ≈ 0.81%이며, 3배인 약 2.5% 안에 거의 항상 들어옴
+const HLL_PRECISION: u32 = 14;
+
+// 레코드를 끝까지 읽으며 컬럼별 서로 다른 (null이 아닌) 값의 개수를 셈. 서로 다른 값이
+// exact_threshold개를 넘은 컬럼은 HashSet을 버리고 HyperLogLog로 바꿔 메모리를 일정하게 유지함
+pub fn distinct_counts<R: Read>(
+    reader: &mut DictReader<R>,
+    exact_threshold: usize,
+) -> Result<HashMap<String, DistinctCount>, Box<dyn Error>> {
+    let mut counters: HashMap<String, Counter> = reader
+        .header
+        .iter()
+        .map(|field| (field.clone(), Counter::Exact(HashSet::new())))
+        .collect();
+    while let Some(record) = reader.read_record()? {
+        for (field, value) in record {
+            if reader.is_null(&value) {
+                continue;
+            }
+            if let Some(counter) = counters.get_mut(&field) {
+                counter.insert(value, exact_threshold);
+            }
+        }
+    }
+    Ok(counters
+        .into_iter()
+        .map(|(field, counter)| (field, counter.count()))
+        .collect())
+}
+
+enum Counter {
+    Exact(HashSet<String>),
+    Approximate(HyperLogLog),
+}
+
+impl Counter {
+    fn insert(&mut self, value: String, exact_threshold: usize) {
+        match self {
+            Counter::Exact(values) => {
+                values.insert(value);
+                if values.len() > exact_threshold {
+                    let mut hll = HyperLogLog::new();
+                    for value in values.iter() {
+                        hll.insert(value);
+                    }
+                    *self = Counter::Approximate(hll);
+                }
+            }
+            Counter::Approximate(hll) => hll.insert(&value),
+        }
+    }
+
+    fn count(&self) -> DistinctCount {
+        match self {
+            Counter::Exact(values) => DistinctCount::Exact(values.len() as u64),
+            Counter::Approximate(hll) => DistinctCount::Approximate(hll.estimate()),
+        }
+    }
+}
+
+struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    fn new() -> Self {
+        HyperLogLog {
+            registers: vec![0; 1 << HLL_PRECISION],
+        }
+    }
+
+    fn insert(&mut self, value: &str) {
+        // DefaultHasher::new()는 고정된 키를 쓰므로 같은 값은 항상 같은 해시가 됨
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        let rank = ((hash << HLL_PRECISION).leading_zeros() + 1).min(64 - HLL_PRECISION + 1) as u8;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        // 작은 범위에서는 linear counting이 더 정확함
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_distinct_counts_exact() -> Result<(), Box<dyn Error>> {
+        let data = "team,name\na,alice\nb,bob\na,carol\n,dave\n".to_string();
+        let mut reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let counts = distinct_counts(&mut reader, DEFAULT_EXACT_THRESHOLD)?;
+        assert_eq!(counts["team"], DistinctCount::Exact(2));
+        assert_eq!(counts["name"], DistinctCount::Exact(4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_counts_approximate_error_bound() -> Result<(), Box<dyn Error>> {
+        let mut data = "id,group\n".to_string();
+        for i in 0..100_000 {
+            data.push_str(&format!("user-{},{}\n", i, i % 10));
+        }
+        let mut reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let counts = distinct_counts(&mut reader, 1_000)?;
+        assert_eq!(counts["group"], DistinctCount::Exact(10));
+
+        let id = counts["id"];
+        assert!(!id.is_exact());
+        // 표준 오차(약 0.81%)의 3배 범위
+        let error = (id.value() as f64 - 100_000.0).abs() / 100_000.0;
+        assert!(error < 0.025, "estimate {} is off by {}", id.value(), error);
+        Ok(())
+    }
+}
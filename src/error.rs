@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    Invalid(String),
+    Other(Box<dyn Error>),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(e) => write!(f, "{}", e),
+            CsvError::Invalid(message) => write!(f, "{}", message),
+            CsvError::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(e) => Some(e),
+            CsvError::Invalid(_) => None,
+            CsvError::Other(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> Self {
+        CsvError::Io(e)
+    }
+}
+
+impl From<String> for CsvError {
+    fn from(message: String) -> Self {
+        CsvError::Invalid(message)
+    }
+}
+
+impl From<&str> for CsvError {
+    fn from(message: &str) -> Self {
+        CsvError::Invalid(message.to_string())
+    }
+}
+
+// 기존 API들이 반환하는 Box<dyn Error>를 그대로 감쌈
+impl From<Box<dyn Error>> for CsvError {
+    fn from(e: Box<dyn Error>) -> Self {
+        CsvError::Other(e)
+    }
+}
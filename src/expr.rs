@@ -0,0 +1,410 @@
+use crate::reader::DictReader;
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::Read;
+
+// 레코드마다 expr을 계산해 target 컬럼에 넣음 (target이 이미 있으면 덮어씀).
+// 식은 생성할 때 한 번만 파싱하며, 헤더에 없는 컬럼을 참조하면 에러.
+//
+// 식 문법:
+//   - 숫자(1, 2.5), 문자열("abc" 또는 'abc'), 컬럼 이름(price, `unit price`)
+//   - 산술 + - * / 와 단항 -: 피연산자를 숫자로 읽을 수 없으면 에러
+//   - 문자열 이어 붙이기 &
+//   - 비교 == != < <= > >=: 양쪽이 모두 숫자면 숫자로, 아니면 문자열로 비교해 true/false
+//   - 괄호
+pub fn eval_column<R: Read>(
+    reader: DictReader<R>,
+    target: &str,
+    expr: &str,
+) -> Result<EvalReader<R>, Box<dyn Error>> {
+    let expr = Parser::new(expr)?.parse()?;
+    let mut columns = Vec::new();
+    expr.columns(&mut columns);
+    for column in columns {
+        if !reader.header.iter().any(|f| f == column) {
+            return Err(format!("Column {:?} is not in the header", column).into());
+        }
+    }
+    let mut header = reader.header.clone();
+    if !header.iter().any(|f| f == target) {
+        header.push(target.to_string());
+    }
+    Ok(EvalReader {
+        reader,
+        header,
+        target: target.to_string(),
+        expr,
+    })
+}
+
+#[derive(Debug)]
+pub struct EvalReader<R: Read> {
+    pub reader: DictReader<R>,
+    // 원래 헤더 + target
+    pub header: Vec<String>,
+    pub target: String,
+    expr: Expr,
+}
+
+impl<R: Read> EvalReader<R> {
+    pub fn read_record(&mut self) -> Result<Option<HashMap<String, String>>, Box<dyn Error>> {
+        let mut record = match self.reader.read_record()? {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        let value = self
+            .expr
+            .eval(&record)
+            .map_err(|e| format!("{} on line {}", e, self.reader.line))?;
+        record.insert(self.target.clone(), value.to_string());
+        Ok(Some(record))
+    }
+}
+
+impl<R: Read> Iterator for EvalReader<R> {
+    type Item = Result<HashMap<String, String>, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Concat,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Num(f64),
+    Str(String),
+    Column(String),
+    Neg(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Num(f64),
+    Str(String),
+    Bool(bool),
+}
+
+impl Value {
+    fn as_num(&self) -> Option<f64> {
+        match self {
+            Value::Num(n) => Some(*n),
+            Value::Str(s) => s.trim().parse().ok(),
+            Value::Bool(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Num(n) => write!(f, "{}", n),
+            Value::Str(s) => write!(f, "{}", s),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+impl Expr {
+    // 식이 참조하는 컬럼 이름들
+    fn columns<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Expr::Num(_) | Expr::Str(_) => {}
+            Expr::Column(name) => out.push(name),
+            Expr::Neg(inner) => inner.columns(out),
+            Expr::Binary(_, left, right) => {
+                left.columns(out);
+                right.columns(out);
+            }
+        }
+    }
+
+    fn eval(&self, record: &HashMap<String, String>) -> Result<Value, String> {
+        let number = |value: Value| {
+            value
+                .as_num()
+                .ok_or_else(|| format!("Cannot use {:?} as a number", value.to_string()))
+        };
+        match self {
+            Expr::Num(n) => Ok(Value::Num(*n)),
+            Expr::Str(s) => Ok(Value::Str(s.clone())),
+            Expr::Column(name) => record
+                .get(name)
+                .map(|v| Value::Str(v.clone()))
+                .ok_or_else(|| format!("Column {:?} is missing from a record", name)),
+            Expr::Neg(inner) => Ok(Value::Num(-number(inner.eval(record)?)?)),
+            Expr::Binary(op, left, right) => {
+                let left = left.eval(record)?;
+                let right = right.eval(record)?;
+                match op {
+                    Op::Concat => Ok(Value::Str(format!("{}{}", left, right))),
+                    Op::Add | Op::Sub | Op::Mul | Op::Div => {
+                        let (a, b) = (number(left)?, number(right)?);
+                        Ok(Value::Num(match op {
+                            Op::Add => a + b,
+                            Op::Sub => a - b,
+                            Op::Mul => a * b,
+                            _ if b == 0.0 => return Err("Division by zero".to_string()),
+                            _ => a / b,
+                        }))
+                    }
+                    _ => {
+                        let ordering = match (left.as_num(), right.as_num()) {
+                            (Some(a), Some(b)) => a.partial_cmp(&b),
+                            _ => Some(left.to_string().cmp(&right.to_string())),
+                        };
+                        let result = match ordering {
+                            Some(ordering) => match op {
+                                Op::Eq => ordering.is_eq(),
+                                Op::Ne => ordering.is_ne(),
+                                Op::Lt => ordering.is_lt(),
+                                Op::Le => ordering.is_le(),
+                                Op::Gt => ordering.is_gt(),
+                                _ => ordering.is_ge(),
+                            },
+                            // NaN과의 비교는 != 만 참
+                            None => *op == Op::Ne,
+                        };
+                        Ok(Value::Bool(result))
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Str(String),
+    Ident(String),
+    Op(Op),
+    LParen,
+    RParen,
+}
+
+// 우선순위가 낮은 것부터: 비교 < & < + - < * / < 단항 -
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(expr: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Parser {
+            tokens: tokenize(expr)?,
+            pos: 0,
+        })
+    }
+
+    fn parse(mut self) -> Result<Expr, Box<dyn Error>> {
+        let expr = self.comparison()?;
+        if let Some(token) = self.tokens.get(self.pos) {
+            return Err(format!("Unexpected {:?} in expression", token).into());
+        }
+        Ok(expr)
+    }
+
+    fn peek_op(&self, ops: &[Op]) -> Option<Op> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) if ops.contains(op) => Some(*op),
+            _ => None,
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Expr, Box<dyn Error>> {
+        let left = self.concat()?;
+        match self.peek_op(&[Op::Eq, Op::Ne, Op::Lt, Op::Le, Op::Gt, Op::Ge]) {
+            Some(op) => {
+                self.pos += 1;
+                let right = self.concat()?;
+                Ok(Expr::Binary(op, Box::new(left), Box::new(right)))
+            }
+            None => Ok(left),
+        }
+    }
+
+    fn concat(&mut self) -> Result<Expr, Box<dyn Error>> {
+        self.binary(&[Op::Concat], Self::additive)
+    }
+
+    fn additive(&mut self) -> Result<Expr, Box<dyn Error>> {
+        self.binary(&[Op::Add, Op::Sub], Self::term)
+    }
+
+    fn term(&mut self) -> Result<Expr, Box<dyn Error>> {
+        self.binary(&[Op::Mul, Op::Div], Self::unary)
+    }
+
+    // 왼쪽 결합 이항 연산
+    fn binary(
+        &mut self,
+        ops: &[Op],
+        next: fn(&mut Self) -> Result<Expr, Box<dyn Error>>,
+    ) -> Result<Expr, Box<dyn Error>> {
+        let mut left = next(self)?;
+        while let Some(op) = self.peek_op(ops) {
+            self.pos += 1;
+            let right = next(self)?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, Box<dyn Error>> {
+        if self.peek_op(&[Op::Sub]).is_some() {
+            self.pos += 1;
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, Box<dyn Error>> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or("Unexpected end of expression")?;
+        self.pos += 1;
+        match token {
+            Token::Num(n) => Ok(Expr::Num(n)),
+            Token::Str(s) => Ok(Expr::Str(s)),
+            Token::Ident(name) => Ok(Expr::Column(name)),
+            Token::LParen => {
+                let expr = self.comparison()?;
+                match self.tokens.get(self.pos) {
+                    Some(Token::RParen) => {
+                        self.pos += 1;
+                        Ok(expr)
+                    }
+                    _ => Err("Missing closing parenthesis in expression".into()),
+                }
+            }
+            token => Err(format!("Unexpected {:?} in expression", token).into()),
+        }
+    }
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>, Box<dyn Error>> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '+' => Token::Op(Op::Add),
+            '-' => Token::Op(Op::Sub),
+            '*' => Token::Op(Op::Mul),
+            '/' => Token::Op(Op::Div),
+            '&' => Token::Op(Op::Concat),
+            '=' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Eq),
+            '!' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Ne),
+            '<' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Le),
+            '<' => Token::Op(Op::Lt),
+            '>' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Ge),
+            '>' => Token::Op(Op::Gt),
+            // 문자열 리터럴과 `공백이 있는 컬럼 이름`
+            '"' | '\'' | '`' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some(end) if end == c => break,
+                        Some(ch) => text.push(ch),
+                        None => return Err(format!("Unterminated {} in expression", c).into()),
+                    }
+                }
+                if c == '`' {
+                    Token::Ident(text)
+                } else {
+                    Token::Str(text)
+                }
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut text = c.to_string();
+                while let Some(ch) = chars.next_if(|ch| ch.is_ascii_digit() || *ch == '.') {
+                    text.push(ch);
+                }
+                Token::Num(
+                    text.parse()
+                        .map_err(|_| format!("Invalid number {:?} in expression", text))?,
+                )
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut text = c.to_string();
+                while let Some(ch) = chars.next_if(|ch| ch.is_alphanumeric() || *ch == '_') {
+                    text.push(ch);
+                }
+                Token::Ident(text)
+            }
+            c => return Err(format!("Unexpected character {:?} in expression", c).into()),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    fn reader(data: &str) -> Result<DictReader<Cursor<String>>, Box<dyn Error>> {
+        DictReader::new(Cursor::new(data.to_string()), ReaderOptions::default())
+    }
+
+    #[test]
+    fn test_eval_column_arithmetic() -> Result<(), Box<dyn Error>> {
+        let evaluated = eval_column(
+            reader("item,price,qty\na,2.5,4\nb,3,2")?,
+            "total",
+            "price * qty",
+        )?;
+        assert_eq!(evaluated.header, vec!["item", "price", "qty", "total"]);
+        let records = evaluated.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records[0]["total"], "10");
+        assert_eq!(records[1]["total"], "6");
+        Ok(())
+    }
+
+    #[test]
+    fn test_eval_column_concat_and_compare() -> Result<(), Box<dyn Error>> {
+        let data = "first,last,score\nada,lovelace,90\nalan,turing,7";
+        let mut evaluated = eval_column(reader(data)?, "name", "first & ' ' & last")?;
+        assert_eq!(evaluated.read_record()?.unwrap()["name"], "ada lovelace");
+
+        let records = eval_column(reader(data)?, "pass", "score >= 2 * (1 + 4)")?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records[0]["pass"], "true");
+        assert_eq!(records[1]["pass"], "false");
+        Ok(())
+    }
+
+    #[test]
+    fn test_eval_column_errors() -> Result<(), Box<dyn Error>> {
+        assert!(eval_column(reader("a,b\n1,2")?, "c", "a + missing").is_err());
+        assert!(eval_column(reader("a,b\n1,2")?, "c", "a + ").is_err());
+        assert!(eval_column(reader("a,b\n1,2")?, "c", "(a + b").is_err());
+
+        let mut evaluated = eval_column(reader("a,b\nx,2")?, "c", "a * b")?;
+        let err = evaluated.read_record().unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+        Ok(())
+    }
+}
@@ -0,0 +1,539 @@
+use crate::reader::{DictReader, ReaderOptions, TrimDir};
+use crate::writer::{decimals, DictWriter, WriterOptions};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// key 값이 비어 있거나 null_values에 해당하는 레코드의 처리 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum EmptyKey {
+    #[default]
+    Group,
+    Skip,
+    Error,
+}
+
+// (key 값, 해당 key의 레코드들)
+pub type Group = (String, Vec<HashMap<String, String>>);
+
+// key 컬럼 값별로 레코드를 묶음. 그룹 순서는 key가 처음 등장한 순서
+pub fn group_by<R: Read>(
+    reader: &mut DictReader<R>,
+    key: &str,
+    empty_key: EmptyKey,
+) -> Result<Vec<Group>, Box<dyn Error>> {
+    if !reader.header.iter().any(|f| f == key) {
+        return Err(format!("Key column {:?} is not in the header", key).into());
+    }
+
+    let mut groups: Vec<Group> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    while let Some(record) = reader.read_record()? {
+        let value = record[key].clone();
+        if reader.is_null(&value) {
+            match empty_key {
+                EmptyKey::Group => {}
+                EmptyKey::Skip => continue,
+                EmptyKey::Error => {
+                    return Err(format!("Empty key {:?} on line {}", key, reader.line).into())
+                }
+            }
+        }
+        match index.get(&value) {
+            Some(&i) => groups[i].1.push(record),
+            None => {
+                index.insert(value.clone(), groups.len());
+                groups.push((value, vec![record]));
+            }
+        }
+    }
+    Ok(groups)
+}
+
+// group_by_external에서 그룹마다 계산할 집계. Count 외에는 대상 컬럼 이름을 가지며
+// 비어 있거나 null_values에 해당하는 값은 건너뜀
+#[derive(Debug, Clone, PartialEq)]
+pub enum Agg {
+    Count,
+    Sum(String),
+    Min(String),
+    Max(String),
+}
+
+impl Agg {
+    // 출력 컬럼 이름: count, sum_<컬럼>, min_<컬럼>, max_<컬럼>
+    pub fn output_name(&self) -> String {
+        match self {
+            Agg::Count => "count".to_string(),
+            Agg::Sum(column) => format!("sum_{}", column),
+            Agg::Min(column) => format!("min_{}", column),
+            Agg::Max(column) => format!("max_{}", column),
+        }
+    }
+
+    fn column(&self) -> Option<&str> {
+        match self {
+            Agg::Count => None,
+            Agg::Sum(column) | Agg::Min(column) | Agg::Max(column) => Some(column),
+        }
+    }
+}
+
+// group_by_external이 한 번에 메모리에 모으는 기본 레코드 수
+pub const DEFAULT_MAX_RECORDS_IN_MEMORY: usize = 100_000;
+
+// group_by_external이 한 번에 병합하는 기본 임시 파일 수
+pub const DEFAULT_MAX_CHUNKS_PER_MERGE: usize = 64;
+
+// group_by와 같이 key별로 묶되, 레코드를 모두 메모리에 올리지 않고 집계만 out에 CSV로 기록함.
+// 입력을 일정 개수씩 key로 정렬해 tmp_dir의 임시 파일에 쓴 뒤 병합하며 그룹을 순서대로 집계함.
+// 메모리에는 레코드 최대 DEFAULT_MAX_RECORDS_IN_MEMORY개와 병합 중인 임시 파일
+// 최대 DEFAULT_MAX_CHUNKS_PER_MERGE개의 현재 레코드만 올라가므로 메모리 사용량은 레코드 수가 아니라
+// 레코드 크기에 따라 늘어나고, 임시 파일이 차지하는 디스크 공간은 입력 크기에 비례함.
+// 출력 그룹은 key의 사전순이고 임시 파일은 끝나면 삭제됨. 빈 key는 group_by와 같이 empty_key에 따라 처리
+pub fn group_by_external<R: Read, W: Write>(
+    reader: &mut DictReader<R>,
+    key: &str,
+    empty_key: EmptyKey,
+    aggs: &[Agg],
+    out: W,
+    tmp_dir: &Path,
+) -> Result<(), Box<dyn Error>> {
+    group_by_external_with_budget(
+        reader,
+        key,
+        empty_key,
+        aggs,
+        out,
+        tmp_dir,
+        DEFAULT_MAX_RECORDS_IN_MEMORY,
+        DEFAULT_MAX_CHUNKS_PER_MERGE,
+    )
+}
+
+// group_by_external과 같지만 임시 파일 하나에 담을 레코드 수(max_records)와
+// 한 번에 병합할 임시 파일 수(max_chunks, 최소 2)를 지정함
+#[allow(clippy::too_many_arguments)]
+pub fn group_by_external_with_budget<R: Read, W: Write>(
+    reader: &mut DictReader<R>,
+    key: &str,
+    empty_key: EmptyKey,
+    aggs: &[Agg],
+    out: W,
+    tmp_dir: &Path,
+    max_records: usize,
+    max_chunks: usize,
+) -> Result<(), Box<dyn Error>> {
+    let mut columns = vec![key.to_string()];
+    for column in aggs.iter().filter_map(Agg::column) {
+        if !columns.iter().any(|c| c == column) {
+            columns.push(column.to_string());
+        }
+    }
+    for column in &columns {
+        if !reader.header.contains(column) {
+            return Err(format!("Column {:?} is not in the header", column).into());
+        }
+    }
+
+    let mut chunks = TempChunks {
+        paths: Vec::new(),
+        dir: tmp_dir.to_path_buf(),
+    };
+    let mut buffer: Vec<Vec<String>> = Vec::new();
+    while let Some(record) = reader.read_record()? {
+        if reader.is_null(&record[key]) {
+            match empty_key {
+                EmptyKey::Group => {}
+                EmptyKey::Skip => continue,
+                EmptyKey::Error => {
+                    return Err(format!("Empty key {:?} on line {}", key, reader.line).into())
+                }
+            }
+        }
+        let row = columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let value = &record[column];
+                // 집계 대상 컬럼의 null 값은 빈 값으로 바꿔 기록함
+                if i > 0 && reader.is_null(value) {
+                    String::new()
+                } else {
+                    value.clone()
+                }
+            })
+            .collect();
+        buffer.push(row);
+        if buffer.len() >= max_records.max(1) {
+            chunks.spill(&columns, &mut buffer)?;
+        }
+    }
+    if !buffer.is_empty() {
+        chunks.spill(&columns, &mut buffer)?;
+    }
+
+    // 임시 파일이 max_chunks개 이하가 될 때까지 앞에서부터 max_chunks개씩 병합해 새 임시 파일로 바꿈.
+    // 병합한 파일은 batch가 drop될 때 삭제됨
+    let max_chunks = max_chunks.max(2);
+    while chunks.paths.len() > max_chunks {
+        let batch = TempChunks {
+            paths: chunks.paths.drain(..max_chunks).collect(),
+            dir: tmp_dir.to_path_buf(),
+        };
+        let mut writer = chunks.create(&columns)?;
+        merge_chunks(&batch.paths, key, |record| {
+            writer.writerow(record)?;
+            Ok(())
+        })?;
+        writer.flush()?;
+    }
+
+    let mut fieldnames = vec![key.to_string()];
+    fieldnames.extend(aggs.iter().map(Agg::output_name));
+    let mut writer = DictWriter::new(out, fieldnames, WriterOptions::default());
+    writer.writeheader()?;
+    let mut current: Option<(String, Vec<Accumulator>)> = None;
+    merge_chunks(&chunks.paths, key, |record| {
+        if current.as_ref().map(|(k, _)| k) != Some(&record[key]) {
+            if let Some((k, accs)) = current.take() {
+                write_group(&mut writer, key, aggs, k, &accs)?;
+            }
+            current = Some((
+                record[key].clone(),
+                vec![Accumulator::default(); aggs.len()],
+            ));
+        }
+        if let Some((_, accs)) = current.as_mut() {
+            for (agg, acc) in aggs.iter().zip(accs.iter_mut()) {
+                acc.add(agg, &record)?;
+            }
+        }
+        Ok(())
+    })?;
+    if let Some((k, accs)) = current {
+        write_group(&mut writer, key, aggs, k, &accs)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// 각 임시 파일의 현재 레코드를 key 기준 최소 힙으로 병합해 key 순서대로 emit에 넘김.
+// 값이 기록한 그대로 돌아오도록 공백을 자르지 않고 읽음
+fn merge_chunks<F>(paths: &[PathBuf], key: &str, mut emit: F) -> Result<(), Box<dyn Error>>
+where
+    F: FnMut(HashMap<String, String>) -> Result<(), Box<dyn Error>>,
+{
+    let mut readers = paths
+        .iter()
+        .map(|path| {
+            let options = ReaderOptions {
+                trim_fields: TrimDir::None,
+                ..Default::default()
+            };
+            DictReader::new(File::open(path)?, options)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut heads: Vec<Option<HashMap<String, String>>> = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for (i, chunk) in readers.iter_mut().enumerate() {
+        let head = chunk.read_record()?;
+        if let Some(record) = &head {
+            heap.push(Reverse((record[key].clone(), i)));
+        }
+        heads.push(head);
+    }
+    while let Some(Reverse((_, i))) = heap.pop() {
+        if let Some(record) = heads[i].take() {
+            emit(record)?;
+        }
+        heads[i] = readers[i].read_record()?;
+        if let Some(record) = &heads[i] {
+            heap.push(Reverse((record[key].clone(), i)));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Accumulator {
+    count: u64,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    // Sum을 입력의 소수 자릿수로 출력하기 위한 최대 소수 자릿수
+    decimals: usize,
+}
+
+impl Accumulator {
+    fn add(&mut self, agg: &Agg, record: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+        let Some(column) = agg.column() else {
+            self.count += 1;
+            return Ok(());
+        };
+        let value = record[column].trim();
+        if value.is_empty() {
+            return Ok(());
+        }
+        let number: f64 = value
+            .parse()
+            .map_err(|_| format!("Cannot aggregate {:?} of column {:?}", value, column))?;
+        self.count += 1;
+        self.sum += number;
+        self.decimals = self.decimals.max(decimals(value));
+        self.min = Some(self.min.map_or(number, |m| m.min(number)));
+        self.max = Some(self.max.map_or(number, |m| m.max(number)));
+        Ok(())
+    }
+
+    // 값이 하나도 없으면 Min/Max는 빈 값
+    fn result(&self, agg: &Agg) -> String {
+        match agg {
+            Agg::Count => self.count.to_string(),
+            Agg::Sum(_) => format!("{:.*}", self.decimals, self.sum),
+            Agg::Min(_) => self.min.map(|m| m.to_string()).unwrap_or_default(),
+            Agg::Max(_) => self.max.map(|m| m.to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+fn write_group<W: Write>(
+    writer: &mut DictWriter<W>,
+    key: &str,
+    aggs: &[Agg],
+    value: String,
+    accs: &[Accumulator],
+) -> Result<(), Box<dyn Error>> {
+    let mut row = HashMap::new();
+    row.insert(key.to_string(), value);
+    for (agg, acc) in aggs.iter().zip(accs) {
+        row.insert(agg.output_name(), acc.result(agg));
+    }
+    writer.writerow(row)?;
+    Ok(())
+}
+
+// 정렬된 임시 파일들. drop될 때 파일을 모두 삭제함
+struct TempChunks {
+    paths: Vec<PathBuf>,
+    dir: PathBuf,
+}
+
+impl TempChunks {
+    // 새 임시 파일을 만들고 헤더를 쓴 writer를 돌려줌
+    fn create(&mut self, columns: &[String]) -> Result<DictWriter<File>, Box<dyn Error>> {
+        // 같은 프로세스에서 동시에 여러 번 호출되어도 파일 이름이 겹치지 않도록 번호를 붙임
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let path = self.dir.join(format!(
+            "csvkit-group-{}-{}.csv",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = File::create(&path)?;
+        self.paths.push(path);
+        let mut writer = DictWriter::new(file, columns.to_vec(), WriterOptions::default());
+        writer.writeheader()?;
+        Ok(writer)
+    }
+
+    fn spill(
+        &mut self,
+        columns: &[String],
+        buffer: &mut Vec<Vec<String>>,
+    ) -> Result<(), Box<dyn Error>> {
+        buffer.sort_by(|a, b| a[0].cmp(&b[0]));
+        let mut writer = self.create(columns)?;
+        for row in buffer.drain(..) {
+            writer.writerow(columns.iter().cloned().zip(row).collect::<HashMap<_, _>>())?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for TempChunks {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    const DATA: &str = "team,name\na,alice\n,bob\nb,carol\nNA,dave\na,erin";
+
+    fn reader() -> Result<DictReader<Cursor<String>>, Box<dyn Error>> {
+        let options = ReaderOptions {
+            null_values: vec!["NA".to_string()],
+            ..Default::default()
+        };
+        DictReader::new(Cursor::new(DATA.to_string()), options)
+    }
+
+    #[test]
+    fn test_group_by_empty_key_group() -> Result<(), Box<dyn Error>> {
+        let groups = group_by(&mut reader()?, "team", EmptyKey::Group)?;
+        let keys: Vec<&str> = groups.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, vec!["a", "", "b", "NA"]);
+        assert_eq!(groups[0].1.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_empty_key_skip() -> Result<(), Box<dyn Error>> {
+        let groups = group_by(&mut reader()?, "team", EmptyKey::Skip)?;
+        let keys: Vec<&str> = groups.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, vec!["a", "b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_empty_key_error() -> Result<(), Box<dyn Error>> {
+        let err = group_by(&mut reader()?, "team", EmptyKey::Error).unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_external_matches_group_by() -> Result<(), Box<dyn Error>> {
+        let mut data = "team,score\n".to_string();
+        for i in 0..500 {
+            data.push_str(&format!("t{},{}\n", (i * 7) % 13, i % 50));
+        }
+        let in_memory = group_by(
+            &mut DictReader::new(Cursor::new(data.clone()), ReaderOptions::default())?,
+            "team",
+            EmptyKey::Group,
+        )?;
+        let mut expected: Vec<String> = in_memory
+            .iter()
+            .map(|(team, records)| {
+                let scores: Vec<f64> = records
+                    .iter()
+                    .map(|r| r["score"].parse().unwrap())
+                    .collect();
+                let min = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                let sum: f64 = scores.iter().sum();
+                format!("{},{},{},{},{}", team, scores.len(), sum, min, max)
+            })
+            .collect();
+        expected.sort();
+
+        let aggs = [
+            Agg::Count,
+            Agg::Sum("score".to_string()),
+            Agg::Min("score".to_string()),
+            Agg::Max("score".to_string()),
+        ];
+        let tmp_dir = std::env::temp_dir();
+        // 500 / 3 = 167개의 임시 파일은 max_chunks 4보다 많아 여러 번 나눠 병합됨
+        for (max_records, max_chunks) in [
+            (DEFAULT_MAX_RECORDS_IN_MEMORY, DEFAULT_MAX_CHUNKS_PER_MERGE),
+            (3, DEFAULT_MAX_CHUNKS_PER_MERGE),
+            (3, 4),
+        ] {
+            let mut reader = DictReader::new(Cursor::new(data.clone()), ReaderOptions::default())?;
+            let mut out = Vec::new();
+            group_by_external_with_budget(
+                &mut reader,
+                "team",
+                EmptyKey::Group,
+                &aggs,
+                &mut out,
+                &tmp_dir,
+                max_records,
+                max_chunks,
+            )?;
+            let out = String::from_utf8(out)?;
+            let mut lines = out.lines();
+            assert_eq!(
+                lines.next(),
+                Some("team,count,sum_score,min_score,max_score")
+            );
+            assert_eq!(lines.map(str::to_string).collect::<Vec<_>>(), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_external_keeps_whitespace() -> Result<(), Box<dyn Error>> {
+        let options = || ReaderOptions {
+            trim_fields: TrimDir::None,
+            ..Default::default()
+        };
+        let data = "k,v\n a,1\na,2\n";
+        let in_memory = group_by(
+            &mut DictReader::new(Cursor::new(data.to_string()), options())?,
+            "k",
+            EmptyKey::Group,
+        )?;
+        assert_eq!(in_memory.len(), 2);
+
+        let mut reader = DictReader::new(Cursor::new(data.to_string()), options())?;
+        let mut out = Vec::new();
+        group_by_external_with_budget(
+            &mut reader,
+            "k",
+            EmptyKey::Group,
+            &[Agg::Sum("v".to_string())],
+            &mut out,
+            &std::env::temp_dir(),
+            1,
+            2,
+        )?;
+        assert_eq!(String::from_utf8(out)?, "k,sum_v\r\n a,1\r\na,2\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_external_sum_decimals() -> Result<(), Box<dyn Error>> {
+        let data = "k,v\na,0.1\na,0.2\nb,1\nb,2.50\n";
+        let mut reader = DictReader::new(Cursor::new(data.to_string()), ReaderOptions::default())?;
+        let mut out = Vec::new();
+        group_by_external(
+            &mut reader,
+            "k",
+            EmptyKey::Group,
+            &[Agg::Sum("v".to_string())],
+            &mut out,
+            &std::env::temp_dir(),
+        )?;
+        assert_eq!(String::from_utf8(out)?, "k,sum_v\r\na,0.3\r\nb,3.50\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_external_empty_key() -> Result<(), Box<dyn Error>> {
+        let tmp_dir = std::env::temp_dir();
+        let run = |empty_key| -> Result<String, Box<dyn Error>> {
+            let mut out = Vec::new();
+            group_by_external(
+                &mut reader()?,
+                "team",
+                empty_key,
+                &[Agg::Count],
+                &mut out,
+                &tmp_dir,
+            )?;
+            Ok(String::from_utf8(out)?)
+        };
+        assert_eq!(
+            run(EmptyKey::Group)?,
+            "team,count\r\n,1\r\nNA,1\r\na,2\r\nb,1\r\n"
+        );
+        assert_eq!(run(EmptyKey::Skip)?, "team,count\r\na,2\r\nb,1\r\n");
+        let err = run(EmptyKey::Error).unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+        Ok(())
+    }
+}
@@ -1,2 +1,16 @@
+pub mod adapters;
+pub mod diff;
+#[cfg(feature = "distinct")]
+pub mod distinct;
+pub mod error;
+pub mod expr;
+pub mod group;
+pub mod logfmt;
 pub mod reader;
+pub mod reshape;
+pub mod sink;
+pub mod tee;
+pub mod transform;
+#[cfg(feature = "regex")]
+pub mod validate;
 pub mod writer;
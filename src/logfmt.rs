@@ -0,0 +1,107 @@
+use crate::reader::DictReader;
+use std::error::Error;
+use std::io::{BufWriter, Read, Write};
+
+// 레코드마다 `name=Alice age=30` 형식의 한 줄을 기록. 컬럼 순서는 헤더 순서를 따르고,
+// 빈 값은 `key=`로 기록함. 키는 logfmt_key로 정리한 헤더 이름
+pub fn to_logfmt<R: Read, W: Write>(
+    reader: &mut DictReader<R>,
+    out: W,
+) -> Result<(), Box<dyn Error>> {
+    let mut out = BufWriter::new(out);
+    let keys: Vec<String> = reader.header.iter().map(|f| logfmt_key(f)).collect();
+    while let Some(record) = reader.read_record()? {
+        let mut line = String::new();
+        for (i, (field, key)) in reader.header.iter().zip(&keys).enumerate() {
+            if i > 0 {
+                line.push(' ');
+            }
+            line.push_str(key);
+            line.push('=');
+            line.push_str(&quote_logfmt_value(&record[field]));
+        }
+        line.push('\n');
+        out.write_all(line.as_bytes())?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+// 키는 따옴표로 감쌀 수 없으므로 공백, `=`, `"`, 제어 문자를 `_`로 바꿈. 빈 이름은 `_`
+fn logfmt_key(field: &str) -> String {
+    if field.is_empty() {
+        return "_".to_string();
+    }
+    field
+        .chars()
+        .map(|c| {
+            if c.is_whitespace() || c.is_control() || c == '=' || c == '"' {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+// 공백, `=`, `"`가 들어 있는 값은 따옴표로 감싸고 내부의 `"`와 `\`, 줄바꿈은 이스케이프
+fn quote_logfmt_value(value: &str) -> String {
+    if !value
+        .chars()
+        .any(|c| c.is_whitespace() || c == '=' || c == '"')
+    {
+        return value.to_string();
+    }
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_to_logfmt() -> Result<(), Box<dyn Error>> {
+        let data = "name,age,note\nAlice,30,\nBob Smith,41,a=b\n".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let mut out = Vec::new();
+        to_logfmt(&mut dict_reader, &mut out)?;
+        assert_eq!(
+            String::from_utf8(out)?,
+            "name=Alice age=30 note=\nname=\"Bob Smith\" age=41 note=\"a=b\"\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_quote_logfmt_value() {
+        assert_eq!(quote_logfmt_value("plain"), "plain");
+        assert_eq!(quote_logfmt_value("say \"hi\""), "\"say \\\"hi\\\"\"");
+    }
+
+    #[test]
+    fn test_logfmt_key() -> Result<(), Box<dyn Error>> {
+        assert_eq!(logfmt_key("first name"), "first_name");
+        assert_eq!(logfmt_key("a=b\"c"), "a_b_c");
+        assert_eq!(logfmt_key(""), "_");
+
+        let data = "first name,age\nAlice,30\n".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let mut out = Vec::new();
+        to_logfmt(&mut dict_reader, &mut out)?;
+        assert_eq!(String::from_utf8(out)?, "first_name=Alice age=30\n");
+        Ok(())
+    }
+}
@@ -1,13 +1,7 @@
-use csvkit::{
-    reader::{DictReader, QuoteStyle, ReaderOptions},
-    writer::{DictWriter, WriterOptions},
-};
+use csvkit::writer::{DictWriter, WriterOptions};
 use std::collections::HashMap;
-use std::env;
 use std::error::Error;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader, BufWriter, Cursor, Read, Write};
-use std::str;
 
 fn main() -> Result<(), Box<dyn Error>> {
     let file_name = "random.csv";
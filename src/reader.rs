@@ -1,23 +1,72 @@
-use std::collections::HashMap;
+use crate::error::CsvError;
+use std::collections::{HashMap, VecDeque};
 use std::error::Error;
-use std::io::{BufRead, BufReader, Read}; // Cursor 추가
-use std::str;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read}; // Cursor 추가
+use std::ops::Range;
 
-#[derive(Debug, Clone, Copy)]
+const BOM: char = '\u{feff}';
+
+// 필드 앞뒤 공백을 잘라낼 방향
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TrimDir {
+    // 공백을 그대로 둠
+    None,
+    Start,
+    End,
+    #[default]
+    Both,
+}
+
+impl TrimDir {
+    // buf[range]에서 이 방향의 공백을 제외한 범위
+    fn apply(self, buf: &str, range: Range<usize>) -> Range<usize> {
+        let field = &buf[range.clone()];
+        let begin = match self {
+            TrimDir::Start | TrimDir::Both => range.end - field.trim_start().len(),
+            TrimDir::None | TrimDir::End => range.start,
+        };
+        let end = match self {
+            TrimDir::End | TrimDir::Both => range.start + field.trim_end().len(),
+            TrimDir::None | TrimDir::Start => range.end,
+        };
+        begin..end.max(begin)
+    }
+}
+
+// 레코드와 그 레코드가 차지한 물리적 줄 범위
+pub type LocatedRecord = (HashMap<String, String>, Range<u64>);
+
+// (헤더, 레코드들)
+pub type Table = (Vec<String>, Vec<HashMap<String, String>>);
+
+// 레코드와 그 내용의 해시
+pub type HashedRecord = (HashMap<String, String>, u64);
+
+#[derive(Debug, Clone, Copy, Default)]
 pub enum QuoteStyle {
+    // 모든 필드를 따옴표로 감쌈
     All,
+    // 구분자, quotechar, \n, \r 중 하나라도 포함된 필드만 감쌈
+    #[default]
     Minimal,
+    // 구분자나 quotechar가 포함된 필드만 감쌈. 줄바꿈이 든 필드는 감싸지 않으므로
+    // 다시 읽을 때 레코드가 나뉠 수 있음
+    Necessary,
+    // 숫자로만 이루어지지 않은 필드를 감쌈
     NonNumeric,
+    // 감싸지 않음
     None,
 }
 
-impl Default for QuoteStyle {
-    fn default() -> Self {
-        QuoteStyle::Minimal
-    }
+// read_all_indexed에서 같은 key가 다시 나왔을 때의 처리 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DuplicateKey {
+    #[default]
+    Error,
+    KeepLast,
 }
 
-#[derive(Debug, Clone, Copy)] // Clone and Copy added for testing
 pub struct ReaderOptions {
     pub delimiter: u8,
     pub doublequote: bool,
@@ -26,6 +75,54 @@ pub struct ReaderOptions {
     pub quoting: QuoteStyle,
     pub skipinitialspace: bool,
     pub strict: bool,
+    pub null_values: Vec<String>,
+    pub error_on_inner_bom: bool,
+    pub collapse_delimiters: bool,
+    // 신뢰할 수 없는 입력에서 구분자가 비정상적으로 많은 줄이 필드를 무한정 만들지 않도록 제한
+    pub max_columns: Option<usize>,
+    // get_bool이 참/거짓으로 인식할 값 (참 목록, 거짓 목록). 대소문자를 구분하지 않음
+    pub bool_values: (Vec<String>, Vec<String>),
+    // 헤더와 필드의 공백 처리 (기본값: 양쪽 모두 잘라냄)
+    pub trim_fields: TrimDir,
+    // 컬럼별로 trim_fields 대신 적용할 방향. 목록에 없는 컬럼은 trim_fields를 따름
+    pub trim_columns: Option<HashMap<String, TrimDir>>,
+    // chain_readers에서 두 번째 이후 입력도 첫 줄이 헤더인지 여부. true면 그 헤더는 건너뜀
+    pub subsequent_have_headers: bool,
+    // 레코드를 모두 읽은 뒤 (레코드 수, 읽은 바이트 수)로 한 번만 호출됨
+    pub on_complete: Option<Box<dyn FnOnce(u64, u64)>>,
+}
+
+impl ReaderOptions {
+    // TSV 기본값: 탭 구분자, 따옴표 처리 없음. TSV에는 표준 따옴표 규칙이 없으므로
+    // 필드 안의 탭이나 줄바꿈은 escapechar를 지정해 writer와 같은 방식으로 이스케이프해야 함
+    pub fn tsv() -> Self {
+        ReaderOptions {
+            delimiter: b'\t',
+            quoting: QuoteStyle::None,
+            ..Default::default()
+        }
+    }
+
+    // Python csv 모듈의 excel 방언: 쉼표 구분자, Minimal, doublequote
+    pub fn excel() -> Self {
+        ReaderOptions::default()
+    }
+
+    // Python csv 모듈의 excel-tab 방언: 구분자만 탭이고 나머지는 excel과 같음
+    pub fn excel_tab() -> Self {
+        ReaderOptions {
+            delimiter: b'\t',
+            ..ReaderOptions::excel()
+        }
+    }
+
+    // Python csv 모듈의 unix 방언: 쉼표 구분자, 모든 필드를 따옴표로 감쌈
+    pub fn unix() -> Self {
+        ReaderOptions {
+            quoting: QuoteStyle::All,
+            ..ReaderOptions::excel()
+        }
+    }
 }
 
 impl Default for ReaderOptions {
@@ -38,11 +135,46 @@ impl Default for ReaderOptions {
             quoting: QuoteStyle::Minimal,
             skipinitialspace: false,
             strict: false,
+            null_values: Vec::new(),
+            error_on_inner_bom: false,
+            collapse_delimiters: false,
+            max_columns: None,
+            bool_values: (
+                ["true", "yes", "y", "1"].map(String::from).to_vec(),
+                ["false", "no", "n", "0"].map(String::from).to_vec(),
+            ),
+            trim_fields: TrimDir::Both,
+            trim_columns: None,
+            subsequent_have_headers: false,
+            on_complete: None,
         }
     }
 }
 
-#[derive(Debug)]
+// on_complete 클로저는 Debug를 구현하지 않으므로 직접 구현
+impl fmt::Debug for ReaderOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderOptions")
+            .field("delimiter", &self.delimiter)
+            .field("doublequote", &self.doublequote)
+            .field("escapechar", &self.escapechar)
+            .field("quotechar", &self.quotechar)
+            .field("quoting", &self.quoting)
+            .field("skipinitialspace", &self.skipinitialspace)
+            .field("strict", &self.strict)
+            .field("null_values", &self.null_values)
+            .field("error_on_inner_bom", &self.error_on_inner_bom)
+            .field("collapse_delimiters", &self.collapse_delimiters)
+            .field("max_columns", &self.max_columns)
+            .field("bool_values", &self.bool_values)
+            .field("trim_fields", &self.trim_fields)
+            .field("trim_columns", &self.trim_columns)
+            .field("subsequent_have_headers", &self.subsequent_have_headers)
+            .field("on_complete", &self.on_complete.is_some())
+            .finish()
+    }
+}
+
 pub struct DictReader<R: Read> {
     pub reader: BufReader<R>,
     pub header: Vec<String>,
@@ -53,6 +185,50 @@ pub struct DictReader<R: Read> {
     pub quoting: QuoteStyle,
     pub skipinitialspace: bool,
     pub strict: bool,
+    pub null_values: Vec<String>,
+    pub error_on_inner_bom: bool,
+    pub collapse_delimiters: bool,
+    pub max_columns: Option<usize>,
+    pub bool_values: (Vec<String>, Vec<String>),
+    pub trim_fields: TrimDir,
+    pub trim_columns: Option<HashMap<String, TrimDir>>,
+    pub on_complete: Option<Box<dyn FnOnce(u64, u64)>>,
+    pub line: u64,
+    pub bytes: u64,
+    pub records_read: u64,
+    // read_record/read_record_ref에서 매번 재사용하는 버퍼
+    line_buf: String,
+    field_buf: String,
+    field_bounds: Vec<Range<usize>>,
+    // detect_header가 미리 읽었다가 되돌려 놓은 레코드와 그 물리적 줄 수
+    pending: VecDeque<(String, u64)>,
+}
+
+impl<R: Read + fmt::Debug> fmt::Debug for DictReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DictReader")
+            .field("reader", &self.reader)
+            .field("header", &self.header)
+            .field("delimiter", &self.delimiter)
+            .field("doublequote", &self.doublequote)
+            .field("escapechar", &self.escapechar)
+            .field("quotechar", &self.quotechar)
+            .field("quoting", &self.quoting)
+            .field("skipinitialspace", &self.skipinitialspace)
+            .field("strict", &self.strict)
+            .field("null_values", &self.null_values)
+            .field("error_on_inner_bom", &self.error_on_inner_bom)
+            .field("collapse_delimiters", &self.collapse_delimiters)
+            .field("max_columns", &self.max_columns)
+            .field("bool_values", &self.bool_values)
+            .field("trim_fields", &self.trim_fields)
+            .field("trim_columns", &self.trim_columns)
+            .field("on_complete", &self.on_complete.is_some())
+            .field("line", &self.line)
+            .field("bytes", &self.bytes)
+            .field("records_read", &self.records_read)
+            .finish_non_exhaustive()
+    }
 }
 
 impl<R: Read> Iterator for DictReader<R> {
@@ -69,24 +245,9 @@ impl<R: Read> Iterator for DictReader<R> {
 
 impl<R: Read> DictReader<R> {
     pub fn new(reader: R, options: ReaderOptions) -> Result<Self, Box<dyn Error>> {
-        let mut buf_reader = BufReader::new(reader);
-        let mut header_line = String::new();
-        buf_reader.read_line(&mut header_line)?;
-
-        let header = Self::parse_line(
-            &header_line,
-            options.delimiter,
-            options.doublequote,
-            options.escapechar,
-            options.quotechar,
-            options.quoting,
-            options.skipinitialspace,
-            options.strict,
-        )?;
-
-        Ok(DictReader {
-            reader: buf_reader,
-            header,
+        let mut dict_reader = DictReader {
+            reader: BufReader::new(reader),
+            header: Vec::new(),
             delimiter: options.delimiter,
             doublequote: options.doublequote,
             escapechar: options.escapechar,
@@ -94,18 +255,185 @@ impl<R: Read> DictReader<R> {
             quoting: options.quoting,
             skipinitialspace: options.skipinitialspace,
             strict: options.strict,
-        })
+            null_values: options.null_values,
+            error_on_inner_bom: options.error_on_inner_bom,
+            collapse_delimiters: options.collapse_delimiters,
+            max_columns: options.max_columns,
+            bool_values: options.bool_values,
+            trim_fields: options.trim_fields,
+            trim_columns: options.trim_columns,
+            on_complete: options.on_complete,
+            line: 0,
+            bytes: 0,
+            records_read: 0,
+            line_buf: String::new(),
+            field_buf: String::new(),
+            field_bounds: Vec::new(),
+            pending: VecDeque::new(),
+        };
+
+        // 헤더도 데이터와 같은 방식으로 읽어서 따옴표 안의 줄바꿈을 허용
+        let header_line = dict_reader.read_physical_record()?.unwrap_or_default();
+        let header_line = header_line.strip_prefix(BOM).unwrap_or(&header_line);
+        dict_reader.header = dict_reader.parse(header_line)?;
+
+        Ok(dict_reader)
     }
 
     pub fn read_record(&mut self) -> Result<Option<HashMap<String, String>>, Box<dyn Error>> {
-        let mut current_line = String::new();
-        let bytes_read = self.reader.read_line(&mut current_line)?;
-        if bytes_read == 0 {
+        if !self.read_into_buffers()? {
+            return Ok(None);
+        }
+        Ok(Some(self.record_from_buffers()))
+    }
+
+    fn record_from_buffers(&self) -> HashMap<String, String> {
+        let mut record = HashMap::with_capacity(self.header.len());
+        for (field, bounds) in self.header.iter().zip(&self.field_bounds) {
+            record.insert(field.clone(), self.field_buf[bounds.clone()].to_string());
+        }
+        record
+    }
+
+    // 헤더 앞에 잡다한 줄이 있는 파일에서 앞쪽 max_scan개 레코드 중 헤더를 추정함.
+    // 모든 필드가 비어 있지 않고 숫자가 아니며, 뒤이은 레코드들의 가장 흔한 필드 수와
+    // 같은 첫 레코드를 헤더로 고르고 그 다음 레코드부터 데이터로 읽도록 되돌려 놓음.
+    // 고른 헤더의 시작 줄 번호(1부터)를 반환하며, 맞는 레코드가 없으면 기존 헤더를 유지.
+    // new() 직후, 레코드를 읽기 전에 호출해야 함
+    pub fn detect_header(&mut self, max_scan: usize) -> Result<usize, Box<dyn Error>> {
+        // (시작 줄, 파싱 결과, 원문, 다 읽은 뒤의 line/bytes)
+        let mut rows = vec![(
+            1,
+            Some(self.header.clone()),
+            String::new(),
+            self.line,
+            self.bytes,
+        )];
+        while rows.len() < max_scan {
+            let start_line = self.line + 1;
+            let line = match self.read_physical_record()? {
+                Some(line) => line,
+                None => break,
+            };
+            let fields = self.parse(&line).ok();
+            rows.push((start_line, fields, line, self.line, self.bytes));
+        }
+
+        let looks_like_header = |fields: &[String]| {
+            fields.iter().all(|field| {
+                let field = field.trim();
+                !field.is_empty() && field.parse::<f64>().is_err()
+            })
+        };
+        let chosen = (0..rows.len())
+            .find(|&i| {
+                let fields = match &rows[i].1 {
+                    Some(fields) if looks_like_header(fields) => fields,
+                    _ => return false,
+                };
+                let mut counts: HashMap<usize, usize> = HashMap::new();
+                for (_, later, ..) in &rows[i + 1..] {
+                    if let Some(later) = later {
+                        *counts.entry(later.len()).or_default() += 1;
+                    }
+                }
+                // 가장 흔한 필드 수. 동률이면 더 큰 값을 택함
+                match counts.into_iter().max_by_key(|&(len, count)| (count, len)) {
+                    Some((len, _)) => len == fields.len(),
+                    None => true,
+                }
+            })
+            .unwrap_or(0);
+
+        let (start_line, fields, _, line, bytes) = rows[chosen].clone();
+        let mut previous_line = line;
+        for (_, _, raw, end_line, _) in rows.drain(chosen + 1..) {
+            self.pending.push_back((raw, end_line - previous_line));
+            previous_line = end_line;
+        }
+        self.header = fields.unwrap_or_default();
+        self.line = line;
+        self.bytes = bytes;
+        Ok(start_line as usize)
+    }
+
+    // 빈 줄로 구분된 여러 표를 (헤더, 레코드들)의 목록으로 읽음. 빈 줄 다음의 첫 줄이 새 표의 헤더가 됨
+    // 첫 번째 표의 헤더는 new()에서 이미 읽은 header를 사용
+    pub fn read_tables(&mut self) -> Result<Vec<Table>, Box<dyn Error>> {
+        let mut tables = Vec::new();
+        let mut current: Option<Table> = Some((self.header.clone(), Vec::new()));
+        loop {
+            let start_line = self.line + 1;
+            let line = match self.read_physical_record()? {
+                Some(line) => line,
+                None => break,
+            };
+            if Self::strip_terminator(&line).trim().is_empty() {
+                tables.extend(current.take());
+                continue;
+            }
+            match current.as_mut() {
+                Some((_, records)) => {
+                    self.parse_record(start_line, &line)?;
+                    records.push(self.record_from_buffers());
+                }
+                None => {
+                    self.header = self.parse(&line)?;
+                    current = Some((self.header.clone(), Vec::new()));
+                }
+            }
+        }
+        tables.extend(current);
+        self.complete();
+        Ok(tables)
+    }
+
+    // 필드마다 String을 할당하지 않고 내부 버퍼를 빌려주는 read_record.
+    // 반환된 RecordRef는 다음 read 호출 전까지만 유효하며, 계속 보관하려면 to_owned()로 복사해야 함
+    pub fn read_record_ref(&mut self) -> Result<Option<RecordRef<'_>>, CsvError> {
+        if !self.read_into_buffers()? {
             return Ok(None);
         }
+        Ok(Some(RecordRef {
+            header: &self.header,
+            buf: &self.field_buf,
+            bounds: &self.field_bounds,
+        }))
+    }
+
+    // 다음 레코드를 읽어 field_buf/field_bounds에 파싱. 더 읽을 레코드가 없으면 false
+    fn read_into_buffers(&mut self) -> Result<bool, Box<dyn Error>> {
+        let start_line = self.line + 1;
+        let mut line = std::mem::take(&mut self.line_buf);
+        line.clear();
+        let result = match self.read_physical_record_into(&mut line) {
+            Ok(true) => self.parse_record(start_line, &line).map(|_| true),
+            Ok(false) => {
+                self.complete();
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        };
+        self.line_buf = line;
+        result
+    }
+
+    fn complete(&mut self) {
+        if let Some(on_complete) = self.on_complete.take() {
+            on_complete(self.records_read, self.bytes);
+        }
+    }
+
+    fn parse_record(&mut self, start_line: u64, line: &str) -> Result<(), Box<dyn Error>> {
+        if self.error_on_inner_bom {
+            if let Some(pos) = line.find(BOM) {
+                let line = start_line + line[..pos].matches('\n').count() as u64;
+                return Err(format!("Unexpected BOM on line {}", line).into());
+            }
+        }
 
-        let values = Self::parse_line(
-            &current_line,
+        Self::parse_line_into(
+            Self::strip_terminator(line),
             self.delimiter,
             self.doublequote,
             self.escapechar,
@@ -113,23 +441,60 @@ impl<R: Read> DictReader<R> {
             self.quoting,
             self.skipinitialspace,
             self.strict,
-        )?;
+            self.collapse_delimiters,
+            self.max_columns,
+            &mut self.field_buf,
+            &mut self.field_bounds,
+        )
+        .map_err(|e| format!("{} on line {}", e, start_line))?;
 
-        if values.len() != self.header.len() {
+        if self.field_bounds.len() != self.header.len() {
             return Err(format!(
                 "Number of fields in row does not match header: expected {}, got {}",
                 self.header.len(),
-                values.len()
+                self.field_bounds.len()
             )
             .into());
         }
-
-        let mut record = HashMap::new();
-        for (i, field) in self.header.iter().enumerate() {
-            record.insert(field.clone(), values[i].clone());
+        for (i, bounds) in self.field_bounds.iter_mut().enumerate() {
+            let dir = self
+                .trim_columns
+                .as_ref()
+                .and_then(|columns| columns.get(&self.header[i]))
+                .copied()
+                .unwrap_or(self.trim_fields);
+            *bounds = dir.apply(&self.field_buf, bounds.clone());
         }
 
-        Ok(Some(record))
+        self.records_read += 1;
+        Ok(())
+    }
+
+    // 레코드와 그 레코드가 차지한 물리적 줄 범위 [시작, 끝). 줄 번호는 1부터 시작
+    pub fn read_record_located(&mut self) -> Result<Option<LocatedRecord>, CsvError> {
+        let start_line = self.line + 1;
+        Ok(self
+            .read_record()?
+            .map(|record| (record, start_line..self.line + 1)))
+    }
+
+    // 레코드와 함께 헤더 순서대로 필드를 이어 계산한 FNV-1a 해시를 반환. HashMap의 순회 순서나
+    // 실행 환경과 무관하게 같은 내용이면 같은 값이 나오므로 변경된 행을 찾는 데 쓸 수 있음.
+    // 각 필드 앞에 길이를 넣어 "a,bc"와 "ab,c"가 같은 해시가 되지 않도록 함
+    pub fn read_record_hashed(&mut self) -> Result<Option<HashedRecord>, Box<dyn Error>> {
+        if !self.read_into_buffers()? {
+            return Ok(None);
+        }
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for bounds in &self.field_bounds {
+            let field = self.field_buf[bounds.clone()].as_bytes();
+            let len = (field.len() as u64).to_le_bytes();
+            for byte in len.iter().chain(field) {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        }
+        Ok(Some((self.record_from_buffers(), hash)))
     }
 
     pub fn read_records(&mut self) -> Result<Vec<HashMap<String, String>>, Box<dyn Error>> {
@@ -140,6 +505,192 @@ impl<R: Read> DictReader<R> {
         Ok(records)
     }
 
+    // 파일 전체를 key 컬럼 값 -> 레코드 맵으로 읽음
+    pub fn read_all_indexed(
+        &mut self,
+        key: &str,
+        on_duplicate: DuplicateKey,
+    ) -> Result<HashMap<String, HashMap<String, String>>, Box<dyn Error>> {
+        if !self.header.iter().any(|f| f == key) {
+            return Err(format!("Key column {:?} is not in the header", key).into());
+        }
+        let mut index = HashMap::new();
+        while let Some(record) = self.read_record()? {
+            let value = record[key].clone();
+            if index.insert(value.clone(), record).is_some() && on_duplicate == DuplicateKey::Error
+            {
+                return Err(format!("Duplicate key {:?} on line {}", value, self.line).into());
+            }
+        }
+        Ok(index)
+    }
+
+    // 대략적인 행 수를 알고 있을 때 Vec을 미리 할당해서 읽는 동안의 재할당과 복사를 피함
+    pub fn records_with_hint(
+        &mut self,
+        expected: usize,
+    ) -> Result<Vec<HashMap<String, String>>, Box<dyn Error>> {
+        let mut records = Vec::with_capacity(expected);
+        while let Some(record) = self.read_record()? {
+            records.push(record);
+        }
+        Ok(records)
+    }
+
+    pub fn is_null(&self, value: &str) -> bool {
+        value.is_empty() || self.null_values.iter().any(|null| null == value)
+    }
+
+    // bool_values 기준으로 값을 해석. 어느 목록에도 없으면 None
+    pub fn parse_bool(&self, value: &str) -> Option<bool> {
+        let value = value.trim();
+        let (truthy, falsy) = &self.bool_values;
+        if truthy.iter().any(|t| t.eq_ignore_ascii_case(value)) {
+            Some(true)
+        } else if falsy.iter().any(|f| f.eq_ignore_ascii_case(value)) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    // 레코드의 column 값을 bool로 읽음. null 값이면 None, 인식할 수 없는 값이면 에러
+    pub fn get_bool(
+        &self,
+        record: &HashMap<String, String>,
+        column: &str,
+    ) -> Result<Option<bool>, CsvError> {
+        let value = record
+            .get(column)
+            .ok_or_else(|| format!("Column {:?} is not in the record", column))?;
+        if self.is_null(value) {
+            return Ok(None);
+        }
+        match self.parse_bool(value) {
+            Some(b) => Ok(Some(b)),
+            None => Err(format!(
+                "Cannot parse {:?} of column {:?} as a boolean",
+                value, column
+            )
+            .into()),
+        }
+    }
+
+    // 컬럼별 (비어있지 않은 값 개수, 전체 레코드 수). null_values에 해당하는 값은 빈 값으로 취급
+    pub fn completeness(&mut self) -> Result<HashMap<String, (u64, u64)>, Box<dyn Error>> {
+        let mut counts: HashMap<String, (u64, u64)> = self
+            .header
+            .iter()
+            .map(|field| (field.clone(), (0, 0)))
+            .collect();
+        while let Some(record) = self.read_record()? {
+            for (field, value) in &record {
+                let is_null = self.is_null(value);
+                if let Some((non_empty, total)) = counts.get_mut(field) {
+                    if !is_null {
+                        *non_empty += 1;
+                    }
+                    *total += 1;
+                }
+            }
+        }
+        Ok(counts)
+    }
+
+    // 레코드 하나에 해당하는 줄을 읽음. 따옴표가 닫히지 않은 채 줄이 끝나면 다음 줄까지 이어서 읽음
+    fn read_physical_record(&mut self) -> Result<Option<String>, Box<dyn Error>> {
+        let mut record = String::new();
+        if self.read_physical_record_into(&mut record)? {
+            Ok(Some(record))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn read_physical_record_into(&mut self, record: &mut String) -> Result<bool, Box<dyn Error>> {
+        if let Some((pending, lines)) = self.pending.pop_front() {
+            self.line += lines;
+            self.bytes += pending.len() as u64;
+            record.push_str(&pending);
+            return Ok(true);
+        }
+        loop {
+            let bytes_read = self.reader.read_line(record)?;
+            if bytes_read == 0 {
+                break;
+            }
+            self.line += 1;
+            self.bytes += bytes_read as u64;
+            if !self.ends_in_quote(record) {
+                break;
+            }
+        }
+        Ok(!record.is_empty())
+    }
+
+    // parse_line과 같은 규칙으로 따옴표 상태만 추적. QuoteStyle::None이면 따옴표 대신
+    // 줄 끝의 \n이 escapechar로 이스케이프되어 레코드가 다음 줄로 이어지는지를 봄
+    fn ends_in_quote(&self, line: &str) -> bool {
+        if matches!(self.quoting, QuoteStyle::None) {
+            let Some(escapechar) = self.escapechar else {
+                return false;
+            };
+            let mut escaped_last = false;
+            let mut chars = line.chars();
+            while let Some(c) = chars.next() {
+                escaped_last = c == escapechar as char && chars.next().is_some();
+            }
+            return escaped_last && line.ends_with('\n');
+        }
+        let quotechar = self.quotechar as char;
+        let mut in_quote = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if !in_quote {
+                in_quote = c == quotechar;
+            } else if c == quotechar {
+                if self.doublequote && chars.peek() == Some(&quotechar) {
+                    chars.next();
+                } else {
+                    in_quote = false;
+                }
+            } else if Some(c) == self.escapechar.map(|e| e as char) {
+                chars.next();
+            }
+        }
+        in_quote
+    }
+
+    fn parse(&self, line: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        Self::parse_line(
+            Self::strip_terminator(line),
+            self.delimiter,
+            self.doublequote,
+            self.escapechar,
+            self.quotechar,
+            self.quoting,
+            self.skipinitialspace,
+            self.strict,
+            self.collapse_delimiters,
+            self.max_columns,
+            self.trim_fields,
+        )
+    }
+
+    // 줄 끝의 종결자(\r\n, \n, \r)만 제거. 종결자가 없는 마지막 줄은 그대로 둠
+    fn strip_terminator(line: &str) -> &str {
+        if let Some(stripped) = line.strip_suffix("\r\n") {
+            stripped
+        } else if let Some(stripped) = line.strip_suffix('\n') {
+            stripped
+        } else if let Some(stripped) = line.strip_suffix('\r') {
+            stripped
+        } else {
+            line
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn parse_line(
         line: &str,
         delimiter: u8,
@@ -149,10 +700,54 @@ impl<R: Read> DictReader<R> {
         quoting: QuoteStyle,
         skipinitialspace: bool,
         strict: bool,
+        collapse_delimiters: bool,
+        max_columns: Option<usize>,
+        trim: TrimDir,
     ) -> Result<Vec<String>, Box<dyn Error>> {
-        let mut fields = Vec::new();
-        let mut current_field = String::new();
+        let mut buf = String::new();
+        let mut bounds = Vec::new();
+        Self::parse_line_into(
+            line,
+            delimiter,
+            doublequote,
+            escapechar,
+            quotechar,
+            quoting,
+            skipinitialspace,
+            strict,
+            collapse_delimiters,
+            max_columns,
+            &mut buf,
+            &mut bounds,
+        )?;
+        Ok(bounds
+            .into_iter()
+            .map(|b| buf[trim.apply(&buf, b)].to_string())
+            .collect())
+    }
+
+    // 필드 내용을 모두 buf에 이어 붙이고, 각 필드의 위치를 bounds에 기록. 공백은 호출하는 쪽에서 TrimDir로 처리
+    #[allow(clippy::too_many_arguments)]
+    fn parse_line_into(
+        line: &str,
+        delimiter: u8,
+        doublequote: bool,
+        escapechar: Option<u8>,
+        quotechar: u8,
+        quoting: QuoteStyle,
+        skipinitialspace: bool,
+        strict: bool,
+        collapse_delimiters: bool,
+        max_columns: Option<usize>,
+        buf: &mut String,
+        bounds: &mut Vec<Range<usize>>,
+    ) -> Result<(), Box<dyn Error>> {
+        buf.clear();
+        bounds.clear();
+        let mut field_start = 0;
         let mut in_quote = false;
+        // collapse_delimiters용: 줄 시작 또는 직전 문자가 따옴표 밖의 구분자였는지
+        let mut after_delimiter = true;
         let mut chars = line.chars().peekable();
 
         while let Some(c) = chars.next() {
@@ -160,7 +755,7 @@ impl<R: Read> DictReader<R> {
                 if c == quotechar as char {
                     // 따옴표 닫기 또는 이중 따옴표 처리
                     if doublequote && chars.peek() == Some(&(quotechar as char)) {
-                        current_field.push(quotechar as char);
+                        buf.push(quotechar as char);
                         chars.next(); // Consume the second quote
                     } else {
                         in_quote = false;
@@ -169,30 +764,51 @@ impl<R: Read> DictReader<R> {
                     if c == escapechar as char {
                         // 이스케이프 문자 처리
                         if let Some(next_c) = chars.next() {
-                            current_field.push(next_c);
+                            buf.push(next_c);
                         } else {
                             // 이스케이프 문자 뒤에 문자가 없으면 에러 처리
                             return Err("Invalid escape sequence at the end of the line".into());
                         }
                     } else {
-                        current_field.push(c);
+                        buf.push(c);
                     }
                 } else {
-                    current_field.push(c);
+                    buf.push(c);
                 }
             } else {
                 if c == delimiter as char {
-                    // 필드 구분자
-                    fields.push(current_field.trim().to_string());
-                    current_field.clear();
-                } else if c == quotechar as char {
-                    // 따옴표 열기
+                    // 연속된 구분자는 하나로 취급
+                    if collapse_delimiters && after_delimiter {
+                        continue;
+                    }
+                    // 필드 구분자. 마지막 필드가 하나 더 남아 있으므로 여기서 이미 상한이면 초과
+                    if let Some(max) = max_columns {
+                        if bounds.len() + 1 >= max {
+                            return Err(format!("Record has more than {} columns", max).into());
+                        }
+                    }
+                    bounds.push(field_start..buf.len());
+                    field_start = buf.len();
+                    after_delimiter = true;
+                    continue;
+                }
+                after_delimiter = false;
+                if c == quotechar as char && !matches!(quoting, QuoteStyle::None) {
+                    // 따옴표 열기 (QuoteStyle::None이면 quotechar도 일반 문자로 취급)
                     in_quote = true;
-                } else if skipinitialspace && current_field.is_empty() && c.is_whitespace() {
+                } else if matches!(quoting, QuoteStyle::None)
+                    && Some(c) == escapechar.map(|e| e as char)
+                {
+                    // 따옴표가 없으므로 구분자나 줄바꿈은 escapechar로 이스케이프되어 있음
+                    match chars.next() {
+                        Some(next_c) => buf.push(next_c),
+                        None => return Err("Invalid escape sequence at the end of the line".into()),
+                    }
+                } else if skipinitialspace && buf.len() == field_start && c.is_whitespace() {
                     // skipinitialspace가 true일 때, 구분자 뒤의 공백 무시
                     continue;
                 } else {
-                    current_field.push(c);
+                    buf.push(c);
                 }
             }
         }
@@ -201,9 +817,156 @@ impl<R: Read> DictReader<R> {
             return Err("Unclosed quote in strict mode".into());
         }
 
-        fields.push(current_field.trim().to_string()); // 마지막 필드 추가
-        Ok(fields)
+        // collapse_delimiters일 때 줄 끝의 구분자 뒤에는 빈 필드를 만들지 않음
+        if !(collapse_delimiters && after_delimiter && !bounds.is_empty()) {
+            bounds.push(field_start..buf.len()); // 마지막 필드 추가
+        }
+        Ok(())
+    }
+}
+
+// DictReader 내부 버퍼를 빌려 쓰는 레코드. 필드 값은 할당 없이 &str로 꺼낼 수 있음
+#[derive(Debug)]
+pub struct RecordRef<'a> {
+    header: &'a [String],
+    buf: &'a str,
+    bounds: &'a [Range<usize>],
+}
+
+impl<'a> RecordRef<'a> {
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    pub fn get(&self, column: &str) -> Option<&'a str> {
+        let i = self.header.iter().position(|f| f == column)?;
+        self.get_index(i)
+    }
+
+    pub fn get_index(&self, i: usize) -> Option<&'a str> {
+        self.bounds.get(i).map(|b| &self.buf[b.clone()])
+    }
+
+    // (컬럼 이름, 값)을 헤더 순서대로 순회
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.header
+            .iter()
+            .zip(self.bounds)
+            .map(|(field, b)| (field.as_str(), &self.buf[b.clone()]))
+    }
+
+    pub fn to_owned(&self) -> HashMap<String, String> {
+        self.iter()
+            .map(|(field, value)| (field.to_string(), value.to_string()))
+            .collect()
+    }
+}
+
+// 여러 입력을 순서대로 이어 하나의 DictReader처럼 읽음. 헤더는 첫 입력에서만 읽고,
+// 이후 입력은 subsequent_have_headers가 true면 첫 줄(헤더)을 건너뛰며 그 헤더가 다르면 에러.
+// 입력은 필요할 때 하나씩 읽으며, on_complete는 마지막 입력까지 다 읽었을 때 한 번만 호출됨.
+// line은 입력이 바뀔 때마다 0부터 다시 셈
+pub fn chain_readers(
+    sources: Vec<Box<dyn Read>>,
+    mut options: ReaderOptions,
+) -> Result<ChainedDictReader, Box<dyn Error>> {
+    let mut sources = sources.into_iter();
+    let first = sources.next().ok_or("No sources to chain")?;
+    let subsequent_have_headers = options.subsequent_have_headers;
+    let on_complete = options.on_complete.take();
+    Ok(ChainedDictReader {
+        reader: DictReader::new(first, options)?,
+        subsequent_have_headers,
+        source_index: 0,
+        sources,
+        on_complete,
+    })
+}
+
+pub struct ChainedDictReader {
+    pub reader: DictReader<Box<dyn Read>>,
+    pub subsequent_have_headers: bool,
+    // 지금 읽고 있는 입력의 순번 (0부터)
+    pub source_index: usize,
+    sources: std::vec::IntoIter<Box<dyn Read>>,
+    on_complete: Option<Box<dyn FnOnce(u64, u64)>>,
+}
+
+// Box<dyn Read>는 Debug를 구현하지 않으므로 직접 구현
+impl fmt::Debug for ChainedDictReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChainedDictReader")
+            .field("header", &self.reader.header)
+            .field("subsequent_have_headers", &self.subsequent_have_headers)
+            .field("source_index", &self.source_index)
+            .field("remaining_sources", &self.sources.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChainedDictReader {
+    pub fn read_record(&mut self) -> Result<Option<HashMap<String, String>>, Box<dyn Error>> {
+        loop {
+            if let Some(record) = self.reader.read_record()? {
+                return Ok(Some(record));
+            }
+            let Some(source) = self.sources.next() else {
+                if let Some(on_complete) = self.on_complete.take() {
+                    on_complete(self.reader.records_read, self.reader.bytes);
+                }
+                return Ok(None);
+            };
+            self.reader.reader = BufReader::new(source);
+            self.reader.line = 0;
+            self.source_index += 1;
+            if self.subsequent_have_headers {
+                if let Some(line) = self.reader.read_physical_record()? {
+                    let line = line.strip_prefix(BOM).unwrap_or(&line);
+                    if self.reader.parse(line)? != self.reader.header {
+                        return Err(format!(
+                            "Header of source {} does not match the first source",
+                            self.source_index
+                        )
+                        .into());
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for ChainedDictReader {
+    type Item = Result<HashMap<String, String>, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+// 행 없이 값 하나를 ReaderOptions의 규칙대로 해석. 필드가 둘 이상 나오면 에러
+pub fn unescape_field(value: &str, options: &ReaderOptions) -> Result<String, CsvError> {
+    let mut fields = DictReader::<io::Empty>::parse_line(
+        value,
+        options.delimiter,
+        options.doublequote,
+        options.escapechar,
+        options.quotechar,
+        options.quoting,
+        options.skipinitialspace,
+        options.strict,
+        options.collapse_delimiters,
+        options.max_columns,
+        // 값 하나를 되돌리는 함수이므로 공백을 잘라 데이터를 잃지 않도록 trim_fields는 무시
+        TrimDir::None,
+    )?;
+    if fields.len() != 1 {
+        return Err(format!("Expected a single field, got {}", fields.len()).into());
     }
+    Ok(fields.remove(0))
 }
 
 #[cfg(test)]
@@ -355,4 +1118,397 @@ mod tests {
         assert_eq!(record.get("header2").unwrap(), "value2");
         Ok(())
     }
+
+    #[test]
+    fn test_completeness() -> Result<(), Box<dyn Error>> {
+        let data = "id,name,note\n1,a,x\n2,,NA\n3,c,y".to_string();
+        let cursor = Cursor::new(data);
+        let options = ReaderOptions {
+            null_values: vec!["NA".to_string()],
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(cursor, options)?;
+        let report = dict_reader.completeness()?;
+        assert_eq!(report.get("id"), Some(&(3, 3)));
+        assert_eq!(report.get("name"), Some(&(2, 3)));
+        assert_eq!(report.get("note"), Some(&(2, 3)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_line_without_newline() -> Result<(), Box<dyn Error>> {
+        let mut last_records = Vec::new();
+        for data in [
+            "h1,h2\nx,y\na,b\n",
+            "h1,h2\nx,y\na,b",
+            "h1,h2\r\nx,y\r\na,b\r\n",
+        ] {
+            let cursor = Cursor::new(data.to_string());
+            let mut dict_reader = DictReader::new(cursor, ReaderOptions::default())?;
+            let records = dict_reader.read_records()?;
+            assert_eq!(records.len(), 2);
+            assert_eq!(records[1].get("h2").unwrap(), "b");
+            last_records.push(records[1].clone());
+        }
+        assert_eq!(last_records[0], last_records[1]);
+        assert_eq!(last_records[0], last_records[2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip_terminator() {
+        assert_eq!(
+            DictReader::<Cursor<String>>::strip_terminator("a,b\r\n"),
+            "a,b"
+        );
+        assert_eq!(
+            DictReader::<Cursor<String>>::strip_terminator("a,b\n"),
+            "a,b"
+        );
+        assert_eq!(DictReader::<Cursor<String>>::strip_terminator("a,b"), "a,b");
+        assert_eq!(
+            DictReader::<Cursor<String>>::strip_terminator("a,b \n"),
+            "a,b "
+        );
+    }
+
+    #[test]
+    fn test_multiline_quoted_header() -> Result<(), Box<dyn Error>> {
+        let data = "\"first\nname\",id\n\"a\nb\",1\nc,2\n".to_string();
+        let cursor = Cursor::new(data);
+        let mut dict_reader = DictReader::new(cursor, ReaderOptions::default())?;
+        assert_eq!(dict_reader.header, vec!["first\nname", "id"]);
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record.get("first\nname").unwrap(), "a\nb");
+        assert_eq!(record.get("id").unwrap(), "1");
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record.get("first\nname").unwrap(), "c");
+        assert!(dict_reader.read_record()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_mode_unclosed_quote_in_header() {
+        let data = "\"first\nname,id\na,1".to_string();
+        let cursor = Cursor::new(data);
+        let options = ReaderOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(DictReader::new(cursor, options).is_err());
+    }
+
+    #[test]
+    fn test_records_with_hint() -> Result<(), Box<dyn Error>> {
+        let data = "header1,header2\nvalue1,value2\nvalue3,value4".to_string();
+        let expected =
+            DictReader::new(Cursor::new(data.clone()), ReaderOptions::default())?.read_records()?;
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let records = dict_reader.records_with_hint(16)?;
+        assert!(records.capacity() >= 16);
+        assert_eq!(records, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_leading_bom_is_stripped() -> Result<(), Box<dyn Error>> {
+        let data = "\u{feff}header1,header2\nvalue1,value2".to_string();
+        let options = ReaderOptions {
+            error_on_inner_bom: true,
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data), options)?;
+        assert_eq!(dict_reader.header, vec!["header1", "header2"]);
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record.get("header1").unwrap(), "value1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_on_inner_bom() -> Result<(), Box<dyn Error>> {
+        let data = "\u{feff}header1,header2\nvalue1,value2\n\u{feff}value3,value4".to_string();
+        let options = ReaderOptions {
+            error_on_inner_bom: true,
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data), options)?;
+        assert!(dict_reader.read_record()?.is_some());
+        let err = dict_reader.read_record().unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_collapse_delimiters() -> Result<(), Box<dyn Error>> {
+        let data = "a  b   c\n  1    \"x  y\"   z  \n2 3 4".to_string();
+        let options = ReaderOptions {
+            delimiter: b' ',
+            collapse_delimiters: true,
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data), options)?;
+        assert_eq!(dict_reader.header, vec!["a", "b", "c"]);
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record.get("a").unwrap(), "1");
+        assert_eq!(record.get("b").unwrap(), "x  y");
+        assert_eq!(record.get("c").unwrap(), "z");
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record.get("c").unwrap(), "4");
+        Ok(())
+    }
+
+    #[test]
+    fn test_consecutive_delimiters_without_collapse() -> Result<(), Box<dyn Error>> {
+        let data = "a,b,c\n1,,3".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record.get("b").unwrap(), "");
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_complete() -> Result<(), Box<dyn Error>> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let data = "header1,header2\nvalue1,value2\nvalue3,value4\n".to_string();
+        let data_len = data.len() as u64;
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let calls_in_callback = Rc::clone(&calls);
+        let options = ReaderOptions {
+            on_complete: Some(Box::new(move |records, bytes| {
+                calls_in_callback.borrow_mut().push((records, bytes));
+            })),
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data), options)?;
+        assert_eq!(dict_reader.by_ref().count(), 2);
+        assert!(dict_reader.next().is_none());
+        assert_eq!(*calls.borrow(), vec![(2, data_len)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_located() -> Result<(), Box<dyn Error>> {
+        let data = "id,note\n1,\"two\nlines\"\n2,one line\n".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let (record, range) = dict_reader.read_record_located()?.unwrap();
+        assert_eq!(record.get("note").unwrap(), "two\nlines");
+        assert_eq!(range, 2..4);
+        let (record, range) = dict_reader.read_record_located()?.unwrap();
+        assert_eq!(record.get("id").unwrap(), "2");
+        assert_eq!(range, 4..5);
+        assert!(dict_reader.read_record_located()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_all_indexed() -> Result<(), Box<dyn Error>> {
+        let data = "id,name\n1,alice\n2,bob\n1,carol".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data.clone()), ReaderOptions::default())?;
+        let err = dict_reader
+            .read_all_indexed("id", DuplicateKey::Error)
+            .unwrap_err();
+        assert!(err.to_string().contains("line 4"));
+
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let index = dict_reader.read_all_indexed("id", DuplicateKey::KeepLast)?;
+        assert_eq!(index.len(), 2);
+        assert_eq!(index["1"].get("name").unwrap(), "carol");
+        assert_eq!(index["2"].get("name").unwrap(), "bob");
+        Ok(())
+    }
+
+    #[test]
+    fn test_quote_none_treats_quotechar_as_data() -> Result<(), Box<dyn Error>> {
+        let data = "header1,header2\n\"value1,value2\"".to_string();
+        let options = ReaderOptions {
+            quoting: QuoteStyle::None,
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data), options)?;
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record.get("header1").unwrap(), "\"value1");
+        assert_eq!(record.get("header2").unwrap(), "value2\"");
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_ref() -> Result<(), Box<dyn Error>> {
+        let data = "id,name,note\n1, alice ,\"a \"\"quoted\"\", value\"\n2,bob,\"two\nlines\"\n3,,"
+            .to_string();
+        let expected =
+            DictReader::new(Cursor::new(data.clone()), ReaderOptions::default())?.read_records()?;
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let mut borrowed = Vec::new();
+        while let Some(record) = dict_reader.read_record_ref()? {
+            assert_eq!(record.len(), 3);
+            assert_eq!(record.get("id"), record.get_index(0));
+            borrowed.push(record.to_owned());
+        }
+        assert_eq!(borrowed, expected);
+        assert_eq!(borrowed[0]["name"], "alice");
+        assert_eq!(borrowed[0]["note"], "a \"quoted\", value");
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_columns() -> Result<(), Box<dyn Error>> {
+        let data = format!("a,b,c\n1,2,3\n{}", ",".repeat(100_000));
+        let options = ReaderOptions {
+            max_columns: Some(3),
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data), options)?;
+        assert!(dict_reader.read_record()?.is_some());
+        let err = dict_reader.read_record().unwrap_err();
+        assert!(err.to_string().contains("more than 3 columns on line 3"));
+        assert!(dict_reader.field_bounds.len() <= 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_columns_header() {
+        let options = ReaderOptions {
+            max_columns: Some(2),
+            ..Default::default()
+        };
+        assert!(DictReader::new(Cursor::new("a,b,c\n1,2,3".to_string()), options).is_err());
+    }
+
+    #[test]
+    fn test_read_tables() -> Result<(), Box<dyn Error>> {
+        let data = "id,name\n1,alice\n2,bob\n\n\ncode,price,qty\nA,10,1\n".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let tables = dict_reader.read_tables()?;
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].0, vec!["id", "name"]);
+        assert_eq!(tables[0].1.len(), 2);
+        assert_eq!(tables[0].1[1]["name"], "bob");
+        assert_eq!(tables[1].0, vec!["code", "price", "qty"]);
+        assert_eq!(tables[1].1.len(), 1);
+        assert_eq!(tables[1].1[0]["price"], "10");
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_header() -> Result<(), Box<dyn Error>> {
+        let data = "Exported 2024-01-01\nid,name,score\n1,alice,90\n2,bob,85\n".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        assert_eq!(dict_reader.detect_header(5)?, 2);
+        assert_eq!(dict_reader.header, vec!["id", "name", "score"]);
+        let (record, lines) = dict_reader.read_record_located()?.unwrap();
+        assert_eq!(record["name"], "alice");
+        assert_eq!(lines, 3..4);
+        assert_eq!(dict_reader.read_records()?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_hashed() -> Result<(), Box<dyn Error>> {
+        let data = "id,name\n1,alice\n1,alice\n1,alicf\n1a,lice\n".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let mut hashes = Vec::new();
+        while let Some((record, hash)) = dict_reader.read_record_hashed()? {
+            assert_eq!(record["id"].chars().next(), Some('1'));
+            hashes.push(hash);
+        }
+        assert_eq!(hashes.len(), 4);
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[2]);
+        assert_ne!(hashes[1], hashes[3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_bool() -> Result<(), Box<dyn Error>> {
+        let data = "id,active\n1,yes\n2,No\n3,Y\n4,\n5,maybe\n".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let records = dict_reader.read_records()?;
+        let values: Vec<Option<bool>> = records[..4]
+            .iter()
+            .map(|r| dict_reader.get_bool(r, "active"))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(values, vec![Some(true), Some(false), Some(true), None]);
+        assert!(dict_reader.get_bool(&records[4], "active").is_err());
+
+        let options = ReaderOptions {
+            bool_values: (vec!["on".to_string()], vec!["off".to_string()]),
+            ..Default::default()
+        };
+        let dict_reader = DictReader::new(Cursor::new("a\n".to_string()), options)?;
+        assert_eq!(dict_reader.parse_bool("ON"), Some(true));
+        assert_eq!(dict_reader.parse_bool("yes"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_columns() -> Result<(), Box<dyn Error>> {
+        let data = "code,comment\n  A1  ,  indented note  \n".to_string();
+        let options = ReaderOptions {
+            trim_fields: TrimDir::None,
+            trim_columns: Some(HashMap::from([("code".to_string(), TrimDir::Both)])),
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data.clone()), options)?;
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record["code"], "A1");
+        assert_eq!(record["comment"], "  indented note  ");
+
+        let options = ReaderOptions {
+            trim_columns: Some(HashMap::from([("comment".to_string(), TrimDir::End)])),
+            ..Default::default()
+        };
+        let mut dict_reader = DictReader::new(Cursor::new(data), options)?;
+        let record = dict_reader.read_record()?.unwrap();
+        assert_eq!(record["code"], "A1");
+        assert_eq!(record["comment"], "  indented note");
+        Ok(())
+    }
+
+    #[test]
+    fn test_chain_readers() -> Result<(), Box<dyn Error>> {
+        let totals = std::rc::Rc::new(std::cell::Cell::new((0, 0)));
+        let seen = totals.clone();
+        let options = ReaderOptions {
+            subsequent_have_headers: true,
+            on_complete: Some(Box::new(move |records, bytes| seen.set((records, bytes)))),
+            ..Default::default()
+        };
+        let sources: Vec<Box<dyn Read>> = vec![
+            Box::new(Cursor::new("id,name\n1,alice\n2,bob\n")),
+            Box::new(Cursor::new("id,name\n3,carol")),
+        ];
+        let mut chained = chain_readers(sources, options)?;
+        assert_eq!(chained.reader.header, vec!["id", "name"]);
+        let first = chained.read_record()?.unwrap();
+        assert_eq!(first["name"], "alice");
+        assert_eq!(totals.get(), (0, 0));
+        let records = chained.by_ref().collect::<Result<Vec<_>, _>>()?;
+        let names: Vec<&str> = records.iter().map(|r| r["name"].as_str()).collect();
+        assert_eq!(names, vec!["bob", "carol"]);
+        assert_eq!(totals.get(), (3, 37));
+
+        let sources: Vec<Box<dyn Read>> = vec![
+            Box::new(Cursor::new("id,name\n1,alice\n")),
+            Box::new(Cursor::new("2,bob\n")),
+        ];
+        let records =
+            chain_readers(sources, ReaderOptions::default())?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1]["name"], "bob");
+
+        let sources: Vec<Box<dyn Read>> = vec![
+            Box::new(Cursor::new("id,name\n1,alice\n")),
+            Box::new(Cursor::new("id,email\n2,b@example.com\n")),
+        ];
+        let options = ReaderOptions {
+            subsequent_have_headers: true,
+            ..Default::default()
+        };
+        let mut chained = chain_readers(sources, options)?;
+        chained.read_record()?;
+        assert!(chained.read_record().is_err());
+        Ok(())
+    }
 }
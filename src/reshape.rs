@@ -0,0 +1,239 @@
+use crate::reader::{DictReader, Table};
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::io::Read;
+
+// pandas의 melt처럼 넓은 형식을 긴 형식으로 변환. 입력 레코드 하나에서 value 컬럼마다
+// id 컬럼들 + var_name(원래 컬럼 이름) + value_name(값)으로 이루어진 레코드를 하나씩 만듦.
+// id/value 컬럼이 헤더에 없으면 첫 레코드를 읽기 전에 에러
+pub fn melt<R: Read>(
+    reader: DictReader<R>,
+    id_columns: &[&str],
+    value_columns: &[&str],
+    var_name: &str,
+    value_name: &str,
+) -> Result<MeltReader<R>, Box<dyn Error>> {
+    for column in id_columns.iter().chain(value_columns) {
+        if !reader.header.iter().any(|f| f == column) {
+            return Err(format!("Column {:?} is not in the header", column).into());
+        }
+    }
+    let mut header: Vec<String> = id_columns.iter().map(|c| c.to_string()).collect();
+    header.push(var_name.to_string());
+    header.push(value_name.to_string());
+    Ok(MeltReader {
+        reader,
+        header,
+        id_columns: id_columns.iter().map(|c| c.to_string()).collect(),
+        value_columns: value_columns.iter().map(|c| c.to_string()).collect(),
+        var_name: var_name.to_string(),
+        value_name: value_name.to_string(),
+        pending: VecDeque::new(),
+    })
+}
+
+#[derive(Debug)]
+pub struct MeltReader<R: Read> {
+    pub reader: DictReader<R>,
+    pub header: Vec<String>,
+    pub id_columns: Vec<String>,
+    pub value_columns: Vec<String>,
+    pub var_name: String,
+    pub value_name: String,
+    pending: VecDeque<HashMap<String, String>>,
+}
+
+impl<R: Read> MeltReader<R> {
+    pub fn read_record(&mut self) -> Result<Option<HashMap<String, String>>, Box<dyn Error>> {
+        // value 컬럼이 없으면 만들 레코드도 없음
+        if self.value_columns.is_empty() {
+            return Ok(None);
+        }
+        while self.pending.is_empty() {
+            let record = match self.reader.read_record()? {
+                Some(record) => record,
+                None => return Ok(None),
+            };
+            let get = |column: &String| {
+                record
+                    .get(column)
+                    .cloned()
+                    .ok_or_else(|| format!("Column {:?} is not in the header", column))
+            };
+            let mut ids = HashMap::new();
+            for column in &self.id_columns {
+                ids.insert(column.clone(), get(column)?);
+            }
+            for column in &self.value_columns {
+                let mut melted = ids.clone();
+                melted.insert(self.var_name.clone(), column.clone());
+                melted.insert(self.value_name.clone(), get(column)?);
+                self.pending.push_back(melted);
+            }
+        }
+        Ok(self.pending.pop_front())
+    }
+}
+
+impl<R: Read> Iterator for MeltReader<R> {
+    type Item = Result<HashMap<String, String>, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+// melt의 역변환. column의 서로 다른 값들이 새 컬럼이 되고 value 컬럼의 값으로 채워짐.
+// index 컬럼 값이 같은 레코드끼리 한 행으로 묶이며, 같은 칸에 값이 두 번 들어오면 에러
+pub fn pivot(
+    records: &[HashMap<String, String>],
+    index: &[&str],
+    column: &str,
+    value: &str,
+) -> Result<Table, Box<dyn Error>> {
+    pivot_impl::<fn(&str, &str) -> String>(records, index, column, value, None)
+}
+
+// pivot과 같지만 같은 칸에 값이 두 번 들어오면 agg(기존 값, 새 값)의 결과로 합침
+pub fn pivot_with<F>(
+    records: &[HashMap<String, String>],
+    index: &[&str],
+    column: &str,
+    value: &str,
+    agg: F,
+) -> Result<Table, Box<dyn Error>>
+where
+    F: FnMut(&str, &str) -> String,
+{
+    pivot_impl(records, index, column, value, Some(agg))
+}
+
+fn pivot_impl<F>(
+    records: &[HashMap<String, String>],
+    index: &[&str],
+    column: &str,
+    value: &str,
+    mut agg: Option<F>,
+) -> Result<Table, Box<dyn Error>>
+where
+    F: FnMut(&str, &str) -> String,
+{
+    let get = |record: &HashMap<String, String>, name: &str| {
+        record
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Column {:?} is missing from a record", name))
+    };
+
+    let mut pivot_columns: Vec<String> = Vec::new();
+    let mut rows: Vec<HashMap<String, String>> = Vec::new();
+    let mut row_index: HashMap<Vec<String>, usize> = HashMap::new();
+    for record in records {
+        let key = index
+            .iter()
+            .map(|name| get(record, name))
+            .collect::<Result<Vec<_>, _>>()?;
+        let new_column = get(record, column)?;
+        let new_value = get(record, value)?;
+        if index.contains(&new_column.as_str()) {
+            return Err(
+                format!("Pivot value {:?} collides with an index column", new_column).into(),
+            );
+        }
+        if !pivot_columns.contains(&new_column) {
+            pivot_columns.push(new_column.clone());
+        }
+
+        let i = *row_index.entry(key.clone()).or_insert_with(|| {
+            rows.push(index.iter().map(|s| s.to_string()).zip(key).collect());
+            rows.len() - 1
+        });
+        let row = &mut rows[i];
+        let merged = match (row.get(&new_column), agg.as_mut()) {
+            (None, _) => new_value,
+            (Some(existing), Some(agg)) => agg(existing, &new_value),
+            (Some(_), None) => {
+                return Err(format!(
+                    "Multiple values for column {:?} in the same pivot row",
+                    new_column
+                )
+                .into())
+            }
+        };
+        row.insert(new_column, merged);
+    }
+
+    for row in &mut rows {
+        for name in &pivot_columns {
+            row.entry(name.clone()).or_default();
+        }
+    }
+    let mut header: Vec<String> = index.iter().map(|s| s.to_string()).collect();
+    header.extend(pivot_columns);
+    Ok((header, rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_melt() -> Result<(), Box<dyn Error>> {
+        let data = "id,name,q1,q2\n1,alice,10,20".to_string();
+        let reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let melted = melt(reader, &["id", "name"], &["q1", "q2"], "quarter", "sales")?;
+        assert_eq!(melted.header, vec!["id", "name", "quarter", "sales"]);
+        let records = melted.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["id"], "1");
+        assert_eq!(records[0]["name"], "alice");
+        assert_eq!(records[0]["quarter"], "q1");
+        assert_eq!(records[0]["sales"], "10");
+        assert_eq!(records[1]["quarter"], "q2");
+        assert_eq!(records[1]["sales"], "20");
+        Ok(())
+    }
+
+    #[test]
+    fn test_melt_missing_column() -> Result<(), Box<dyn Error>> {
+        let data = "id,q1\n1,10".to_string();
+        let reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let err = melt(reader, &["id"], &["q1", "q9"], "quarter", "sales").unwrap_err();
+        assert!(err.to_string().contains("q9"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pivot_round_trip() -> Result<(), Box<dyn Error>> {
+        let data = "id,name,q1,q2\n1,alice,10,20\n2,bob,30,40".to_string();
+        let wide =
+            DictReader::new(Cursor::new(data.clone()), ReaderOptions::default())?.read_records()?;
+        let reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let long = melt(reader, &["id", "name"], &["q1", "q2"], "quarter", "sales")?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(long.len(), 4);
+
+        let (header, rows) = pivot(&long, &["id", "name"], "quarter", "sales")?;
+        assert_eq!(header, vec!["id", "name", "q1", "q2"]);
+        assert_eq!(rows, wide);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pivot_collision() -> Result<(), Box<dyn Error>> {
+        let data = "id,key,value\n1,a,1\n1,a,2\n1,b,5".to_string();
+        let records =
+            DictReader::new(Cursor::new(data), ReaderOptions::default())?.read_records()?;
+        assert!(pivot(&records, &["id"], "key", "value").is_err());
+
+        let (_, rows) = pivot_with(&records, &["id"], "key", "value", |a, b| {
+            (a.parse::<i64>().unwrap() + b.parse::<i64>().unwrap()).to_string()
+        })?;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0]["a"], "3");
+        assert_eq!(rows[0]["b"], "5");
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::error::CsvError;
+use crate::reader::DictReader;
+use crate::writer::DictWriter;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::mpsc::Sender;
+
+pub trait RecordSink {
+    fn put(&mut self, record: &HashMap<String, String>) -> Result<(), CsvError>;
+}
+
+impl<W: Write> RecordSink for DictWriter<W> {
+    fn put(&mut self, record: &HashMap<String, String>) -> Result<(), CsvError> {
+        self.writerow(record.clone())?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VecSink {
+    pub records: Vec<HashMap<String, String>>,
+}
+
+impl VecSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RecordSink for VecSink {
+    fn put(&mut self, record: &HashMap<String, String>) -> Result<(), CsvError> {
+        self.records.push(record.clone());
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ChannelSink {
+    pub sender: Sender<HashMap<String, String>>,
+}
+
+impl ChannelSink {
+    pub fn new(sender: Sender<HashMap<String, String>>) -> Self {
+        Self { sender }
+    }
+}
+
+impl RecordSink for ChannelSink {
+    fn put(&mut self, record: &HashMap<String, String>) -> Result<(), CsvError> {
+        self.sender
+            .send(record.clone())
+            .map_err(|_| CsvError::from("Channel receiver has been dropped"))
+    }
+}
+
+// reader의 남은 레코드를 모두 sink로 보내고, 보낸 레코드 수를 반환
+pub fn copy_records<R, S>(reader: &mut DictReader<R>, sink: &mut S) -> Result<u64, CsvError>
+where
+    R: Read,
+    S: RecordSink + ?Sized,
+{
+    let mut count = 0;
+    while let Some(record) = reader.read_record()? {
+        sink.put(&record)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use crate::writer::WriterOptions;
+    use std::error::Error;
+    use std::io::Cursor;
+    use std::sync::mpsc;
+
+    const DATA: &str = "id,name\n1,\"alice, a\"\n2,bob";
+
+    #[test]
+    fn test_vec_sink_matches_dict_writer() -> Result<(), Box<dyn Error>> {
+        let mut reader = DictReader::new(Cursor::new(DATA.to_string()), ReaderOptions::default())?;
+        let mut vec_sink = VecSink::new();
+        assert_eq!(copy_records(&mut reader, &mut vec_sink)?, 2);
+
+        let mut buffer = Cursor::new(Vec::new());
+        let mut reader = DictReader::new(Cursor::new(DATA.to_string()), ReaderOptions::default())?;
+        {
+            let mut writer =
+                DictWriter::new(&mut buffer, reader.header.clone(), WriterOptions::default());
+            writer.writeheader()?;
+            copy_records(&mut reader, &mut writer)?;
+        }
+        buffer.set_position(0);
+        let mut written = DictReader::new(buffer, ReaderOptions::default())?;
+        assert_eq!(written.read_records()?, vec_sink.records);
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_sink() -> Result<(), Box<dyn Error>> {
+        let (sender, receiver) = mpsc::channel();
+        let mut reader = DictReader::new(Cursor::new(DATA.to_string()), ReaderOptions::default())?;
+        let mut sink = ChannelSink::new(sender);
+        copy_records(&mut reader, &mut sink)?;
+        drop(sink);
+        let received: Vec<_> = receiver.iter().collect();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[1].get("name").unwrap(), "bob");
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::{self, Write};
+
+// 같은 내용을 두 writer에 모두 기록. DictWriter::new의 W로 그대로 사용할 수 있음
+#[derive(Debug)]
+pub struct TeeWriter<W1: Write, W2: Write> {
+    pub first: W1,
+    pub second: W2,
+}
+
+impl<W1: Write, W2: Write> TeeWriter<W1, W2> {
+    pub fn new(first: W1, second: W2) -> Self {
+        Self { first, second }
+    }
+
+    pub fn into_inner(self) -> (W1, W2) {
+        (self.first, self.second)
+    }
+}
+
+impl<W1: Write, W2: Write> Write for TeeWriter<W1, W2> {
+    // 한쪽에만 일부가 기록되는 일이 없도록 항상 buf 전체를 양쪽에 기록
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.first.write_all(buf)?;
+        self.second.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.first.flush()?;
+        self.second.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{DictWriter, WriterOptions};
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    #[test]
+    fn test_tee_writer() -> Result<(), Box<dyn Error>> {
+        let mut first = Vec::new();
+        let mut second = Vec::new();
+        {
+            let tee = TeeWriter::new(&mut first, &mut second);
+            let fieldnames = vec!["header1".to_string(), "header2".to_string()];
+            let mut writer = DictWriter::new(tee, fieldnames, WriterOptions::default());
+            writer.writeheader()?;
+            for i in 0..2 {
+                let mut row = HashMap::new();
+                row.insert("header1".to_string(), format!("value1_{}", i));
+                row.insert("header2".to_string(), format!("value2_{}", i));
+                writer.writerow(row)?;
+            }
+        }
+        let expected = "header1,header2\r\nvalue1_0,value2_0\r\nvalue1_1,value2_1\r\n";
+        assert_eq!(String::from_utf8(first)?, expected);
+        assert_eq!(String::from_utf8(second)?, expected);
+        Ok(())
+    }
+}
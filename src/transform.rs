@@ -0,0 +1,66 @@
+use crate::reader::{DictReader, ReaderOptions};
+use crate::writer::{DictWriter, WriterOptions};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{Read, Write};
+
+// input을 읽어 f를 적용한 뒤 output에 기록. f가 None을 반환하면 해당 행은 버려짐
+// 출력 헤더는 입력 헤더를 그대로 사용하며, 기록된 행의 수를 반환
+pub fn transform<R, W, F>(
+    input: R,
+    output: W,
+    rin: ReaderOptions,
+    rout: WriterOptions,
+    mut f: F,
+) -> Result<u64, Box<dyn Error>>
+where
+    R: Read,
+    W: Write,
+    F: FnMut(HashMap<String, String>) -> Option<HashMap<String, String>>,
+{
+    let mut reader = DictReader::new(input, rin)?;
+    let mut writer = DictWriter::new(output, reader.header.clone(), rout);
+    writer.writeheader()?;
+
+    let mut rows_written = 0;
+    while let Some(record) = reader.read_record()? {
+        if let Some(record) = f(record) {
+            writer.writerow(record)?;
+            rows_written += 1;
+        }
+    }
+    writer.flush()?;
+    Ok(rows_written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_transform() -> Result<(), Box<dyn Error>> {
+        let input = Cursor::new("id,name\n1,alice\n2,bob\n3,carol\n4,dave".to_string());
+        let mut buffer = Cursor::new(Vec::new());
+        let mut row_index = 0;
+        let rows_written = transform(
+            input,
+            &mut buffer,
+            ReaderOptions::default(),
+            WriterOptions::default(),
+            |mut record| {
+                row_index += 1;
+                if row_index % 2 == 1 {
+                    return None;
+                }
+                let name = record.get("name")?.to_uppercase();
+                record.insert("name".to_string(), name);
+                Some(record)
+            },
+        )?;
+        assert_eq!(rows_written, 2);
+        let contents = String::from_utf8(buffer.into_inner())?;
+        assert_eq!(contents, "id,name\r\n2,BOB\r\n4,DAVE\r\n");
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::reader::DictReader;
+use regex::Regex;
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::Read;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternViolation {
+    pub line: u64,
+    pub column: String,
+    pub value: String,
+}
+
+impl<R: Read> DictReader<R> {
+    // 컬럼별 정규식으로 모든 레코드를 검사하고 위반 사항을 모두 모아 반환
+    // 정규식은 값 전체와 일치해야 하며, patterns에 없는 컬럼은 검사하지 않음
+    pub fn validate_patterns(
+        &mut self,
+        patterns: &HashMap<String, String>,
+    ) -> Result<Vec<PatternViolation>, Box<dyn Error>> {
+        let mut compiled = Vec::new();
+        for (column, pattern) in patterns {
+            if !self.header.contains(column) {
+                return Err(format!("Column {:?} is not in the header", column).into());
+            }
+            compiled.push((column, Regex::new(&format!("^(?:{})$", pattern))?));
+        }
+
+        let mut violations = Vec::new();
+        loop {
+            let line = self.line + 1;
+            let record = match self.read_record()? {
+                Some(record) => record,
+                None => break,
+            };
+            for (column, regex) in &compiled {
+                let value = &record[*column];
+                if !regex.is_match(value) {
+                    violations.push(PatternViolation {
+                        line,
+                        column: column.to_string(),
+                        value: value.clone(),
+                    });
+                }
+            }
+        }
+        violations.sort_by(|a, b| (a.line, &a.column).cmp(&(b.line, &b.column)));
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderOptions;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_validate_patterns() -> Result<(), Box<dyn Error>> {
+        let data =
+            "id,email,note\n1,a@example.com,x\n2,not-an-email,y\n3,c@example.org,z".to_string();
+        let mut patterns = HashMap::new();
+        patterns.insert("email".to_string(), r"[^@\s]+@[^@\s]+\.[a-z]+".to_string());
+        patterns.insert("id".to_string(), r"\d+".to_string());
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        let violations = dict_reader.validate_patterns(&patterns)?;
+        assert_eq!(
+            violations,
+            vec![PatternViolation {
+                line: 3,
+                column: "email".to_string(),
+                value: "not-an-email".to_string(),
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_patterns_unknown_column() -> Result<(), Box<dyn Error>> {
+        let mut patterns = HashMap::new();
+        patterns.insert("phone".to_string(), r"\d+".to_string());
+        let data = "id,email\n1,a@example.com".to_string();
+        let mut dict_reader = DictReader::new(Cursor::new(data), ReaderOptions::default())?;
+        assert!(dict_reader.validate_patterns(&patterns).is_err());
+        Ok(())
+    }
+}
@@ -1,8 +1,65 @@
+use crate::error::CsvError;
 use crate::reader::QuoteStyle;
+use std::collections::HashMap;
 use std::error::Error;
-use std::io::{BufWriter, Write}; // Cursor 추가
-use std::str;
-use std::{collections::HashMap, fmt::Display, hash::Hash};
+use std::io::{BufWriter, Seek, SeekFrom, Write}; // Cursor 추가
+
+#[derive(Debug, Clone)]
+pub enum ColumnFormat {
+    Float {
+        decimals: usize,
+    },
+    Integer,
+    // fmt는 입력 날짜의 형식. 출력은 항상 ISO(%Y-%m-%d)
+    #[cfg(feature = "chrono")]
+    Date {
+        fmt: String,
+    },
+}
+
+impl ColumnFormat {
+    // 변환할 수 없는 값이면 None
+    fn format(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        match self {
+            ColumnFormat::Float { decimals } => {
+                let number: f64 = value.parse().ok()?;
+                Some(format!("{:.*}", decimals, number))
+            }
+            ColumnFormat::Integer => {
+                if let Ok(number) = value.parse::<i64>() {
+                    return Some(number.to_string());
+                }
+                let number: f64 = value.parse().ok()?;
+                if number.fract() == 0.0 && number.abs() < i64::MAX as f64 {
+                    Some((number as i64).to_string())
+                } else {
+                    None
+                }
+            }
+            #[cfg(feature = "chrono")]
+            ColumnFormat::Date { fmt } => {
+                let date = chrono::NaiveDate::parse_from_str(value, fmt)
+                    .or_else(|_| chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+                    .ok()?;
+                Some(date.format("%Y-%m-%d").to_string())
+            }
+        }
+    }
+}
+
+// new_infer_header에서 첫 레코드의 키로 fieldnames를 만들 때의 순서
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum HeaderOrder {
+    // 키를 처음 본 순서대로 두되 같은 레코드에서 나온 키끼리는 정렬함. 헤더는 첫 레코드
+    // 하나(HashMap)로만 만들고 그 키들은 모두 동시에 처음 보이므로 결과는 Alphabetical과 같음
+    FirstSeenSorted,
+    // 이름순으로 정렬해 실행할 때마다 같은 헤더가 나오도록 함
+    #[default]
+    Alphabetical,
+    // HashMap의 순회 순서를 그대로 사용하므로 실행마다 달라질 수 있음
+    AsProvided,
+}
 
 #[derive(Debug, Clone)]
 pub struct WriterOptions {
@@ -14,6 +71,49 @@ pub struct WriterOptions {
     pub skipinitialspace: bool,
     pub strict: bool,
     pub lineterminator: String,
+    pub column_formats: HashMap<String, ColumnFormat>,
+    pub header_order: HeaderOrder,
+    // Some이면 필드 안의 \n, \r\n, \r을 모두 이 문자열로 바꾼 뒤 따옴표 처리
+    pub normalize_field_newlines: Option<String>,
+    // 행에 없는 키의 컬럼에 채울 값
+    pub restval: String,
+    // true면 행에 있는 빈 값을 ""로 기록해 restval로 채워진 빈 컬럼(NULL)과 구분함
+    pub quote_empty: bool,
+}
+
+impl WriterOptions {
+    // TSV 기본값: 탭 구분자, 따옴표 없음, \n 종결자. 필드 안의 탭이나 줄바꿈은
+    // 따옴표로 감쌀 수 없으므로 escapechar가 없으면 에러, 있으면 escapechar로 이스케이프됨
+    pub fn tsv() -> Self {
+        WriterOptions {
+            delimiter: b'\t',
+            quoting: QuoteStyle::None,
+            lineterminator: "\n".to_string(),
+            ..Default::default()
+        }
+    }
+
+    // Python csv 모듈의 excel 방언: 쉼표 구분자, \r\n 종결자, Minimal, doublequote
+    pub fn excel() -> Self {
+        WriterOptions::default()
+    }
+
+    // Python csv 모듈의 excel-tab 방언: 구분자만 탭이고 나머지는 excel과 같음
+    pub fn excel_tab() -> Self {
+        WriterOptions {
+            delimiter: b'\t',
+            ..WriterOptions::excel()
+        }
+    }
+
+    // Python csv 모듈의 unix 방언: 쉼표 구분자, \n 종결자, 모든 필드를 따옴표로 감쌈
+    pub fn unix() -> Self {
+        WriterOptions {
+            quoting: QuoteStyle::All,
+            lineterminator: "\n".to_string(),
+            ..WriterOptions::excel()
+        }
+    }
 }
 
 impl Default for WriterOptions {
@@ -27,6 +127,11 @@ impl Default for WriterOptions {
             skipinitialspace: false,
             strict: false,
             lineterminator: "\r\n".to_string(),
+            column_formats: HashMap::new(),
+            header_order: HeaderOrder::Alphabetical,
+            normalize_field_newlines: None,
+            restval: String::new(),
+            quote_empty: false,
         }
     }
 }
@@ -39,6 +144,7 @@ where
     pub writer: BufWriter<W>,
     pub fieldnames: Vec<String>,
     pub options: WriterOptions,
+    pub infer_header: bool,
 }
 
 impl<W> DictWriter<W>
@@ -48,8 +154,19 @@ where
     pub fn new(writer: W, fieldnames: Vec<String>, options: WriterOptions) -> Self {
         Self {
             writer: BufWriter::new(writer),
-            fieldnames: fieldnames,
+            fieldnames,
             options,
+            infer_header: false,
+        }
+    }
+
+    // fieldnames는 첫 번째 writerow 호출 시 레코드의 키로 결정되고, 헤더도 그때 함께 기록됨
+    pub fn new_infer_header(writer: W, options: WriterOptions) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            fieldnames: Vec::new(),
+            options,
+            infer_header: true,
         }
     }
 
@@ -72,19 +189,44 @@ where
     where
         V: ToString + Clone,
     {
+        let mut header_bytes = 0;
+        if self.infer_header {
+            if self.fieldnames.is_empty() {
+                // 빈 행에서는 헤더를 만들 수 없고, 빈 헤더를 쓰면 다음 행에서 헤더가 다시 기록됨
+                if row.is_empty() {
+                    return Err("Cannot infer a header from an empty row".into());
+                }
+                self.fieldnames = row.keys().cloned().collect();
+                match self.options.header_order {
+                    HeaderOrder::FirstSeenSorted | HeaderOrder::Alphabetical => {
+                        self.fieldnames.sort()
+                    }
+                    HeaderOrder::AsProvided => {}
+                }
+                header_bytes = self.writeheader()?;
+            } else if row.len() != self.fieldnames.len()
+                || self.fieldnames.iter().any(|f| !row.contains_key(f))
+            {
+                return Err("Row keys do not match the inferred header".into());
+            }
+        }
+
         let mut csv_row = String::new();
         for (i, fieldname) in self.fieldnames.iter().enumerate() {
             let key = fieldname.clone();
-            let value_str = match row.get(&key) {
-                Some(v) =>
-                {
+            let quoted_value = match row.get(&key) {
+                Some(v) => {
                     #[allow(clippy::clone_on_copy)]
-                    v.clone().to_string()
+                    let value_str = v.clone().to_string();
+                    let value_str = self.format_value(fieldname, value_str)?;
+                    self.quote_value(&value_str)?
+                }
+                // quote_empty일 때 빈 restval까지 ""가 되면 빈 값과 구분할 수 없으므로 그대로 둠
+                None if self.options.quote_empty && self.options.restval.is_empty() => {
+                    String::new()
                 }
-                None => "".to_string(),
+                None => self.quote_value(&self.options.restval)?,
             };
-
-            let quoted_value = self.quote_value(&value_str)?;
             csv_row.push_str(&quoted_value);
             if i < self.fieldnames.len() - 1 {
                 csv_row.push(self.options.delimiter as char);
@@ -93,51 +235,33 @@ where
         csv_row.push_str(&self.options.lineterminator);
         let bytes_written = self.writer.write(csv_row.as_bytes())?;
         self.writer.flush()?;
-        Ok(bytes_written)
+        Ok(header_bytes + bytes_written)
     }
 
-    fn quote_value(&self, value: &String) -> Result<String, Box<dyn Error>> {
-        let needs_quotes = match self.options.quoting {
-            QuoteStyle::All => true,
-            QuoteStyle::Minimal => {
-                value.contains(self.options.delimiter as char)
-                    || value.contains(self.options.quotechar as char)
-                    || value.contains('\n')
-                    || value.contains('\r')
-            }
-            QuoteStyle::NonNumeric => !value.chars().all(|c| c.is_numeric()),
-            QuoteStyle::None => false,
+    // column_formats에 지정된 컬럼이면 값을 다시 포맷. 빈 값은 그대로 두고,
+    // 변환할 수 없는 값은 strict일 때만 에러
+    fn format_value(&self, fieldname: &str, value: String) -> Result<String, Box<dyn Error>> {
+        let Some(format) = self.options.column_formats.get(fieldname) else {
+            return Ok(value);
         };
-
-        if needs_quotes {
-            let mut quoted_value = String::new();
-            quoted_value.push(self.options.quotechar as char);
-
-            for c in value.chars() {
-                if c == self.options.quotechar as char {
-                    if self.options.doublequote {
-                        quoted_value.push(self.options.quotechar as char);
-                        quoted_value.push(self.options.quotechar as char);
-                    } else if let Some(escapechar) = self.options.escapechar {
-                        quoted_value.push(escapechar as char);
-                        quoted_value.push(self.options.quotechar as char);
-                    } else {
-                        return Err(
-                            "Need to escape the quote character but no escapechar is set".into(),
-                        );
-                    }
-                } else {
-                    quoted_value.push(c);
-                }
-            }
-
-            quoted_value.push(self.options.quotechar as char);
-            Ok(quoted_value)
-        } else {
-            Ok(value.to_string())
+        if value.is_empty() {
+            return Ok(value);
+        }
+        match format.format(&value) {
+            Some(formatted) => Ok(formatted),
+            None if self.options.strict => Err(format!(
+                "Cannot format value {:?} of column {:?} as {:?}",
+                value, fieldname, format
+            )
+            .into()),
+            None => Ok(value),
         }
     }
 
+    fn quote_value(&self, value: &str) -> Result<String, Box<dyn Error>> {
+        Ok(escape_field(value, &self.options)?)
+    }
+
     pub fn writerows<V>(&mut self, rows: Vec<HashMap<String, V>>) -> Result<usize, Box<dyn Error>>
     where
         V: ToString + Clone,
@@ -155,6 +279,328 @@ where
     }
 }
 
+impl<W> DictWriter<W>
+where
+    W: Write + Seek,
+{
+    // 출력이 비어 있을 때만 헤더를 기록하고, 이미 내용이 있으면 0을 반환.
+    // 이어쓰기 작업을 다시 시작해도 헤더가 중복되지 않음. 쓰기 위치는 출력의 끝으로 옮겨짐
+    pub fn writeheader_if_needed(&mut self) -> Result<usize, Box<dyn Error>> {
+        if self.writer.seek(SeekFrom::End(0))? > 0 {
+            return Ok(0);
+        }
+        self.writeheader()
+    }
+}
+
+// 행 없이 값 하나만 WriterOptions의 규칙대로 따옴표 처리/이스케이프
+pub fn escape_field(value: &str, options: &WriterOptions) -> Result<String, CsvError> {
+    let normalized;
+    let value = match &options.normalize_field_newlines {
+        Some(newline) => {
+            normalized = normalize_newlines(value, newline);
+            normalized.as_str()
+        }
+        None => value,
+    };
+
+    let needs_quotes = match options.quoting {
+        QuoteStyle::All => true,
+        QuoteStyle::Minimal => {
+            value.contains(options.delimiter as char)
+                || value.contains(options.quotechar as char)
+                || value.contains('\n')
+                || value.contains('\r')
+        }
+        QuoteStyle::Necessary => {
+            value.contains(options.delimiter as char) || value.contains(options.quotechar as char)
+        }
+        QuoteStyle::NonNumeric => !value.chars().all(|c| c.is_numeric()),
+        QuoteStyle::None => false,
+    };
+    let needs_quotes = needs_quotes
+        || (options.quote_empty
+            && value.is_empty()
+            && !matches!(options.quoting, QuoteStyle::None));
+
+    if needs_quotes {
+        let mut quoted_value = String::new();
+        quoted_value.push(options.quotechar as char);
+
+        for c in value.chars() {
+            if c == options.quotechar as char {
+                if options.doublequote {
+                    quoted_value.push(options.quotechar as char);
+                    quoted_value.push(options.quotechar as char);
+                } else if let Some(escapechar) = options.escapechar {
+                    quoted_value.push(escapechar as char);
+                    quoted_value.push(options.quotechar as char);
+                } else {
+                    return Err(
+                        "Need to escape the quote character but no escapechar is set".into(),
+                    );
+                }
+            } else {
+                quoted_value.push(c);
+            }
+        }
+
+        quoted_value.push(options.quotechar as char);
+        Ok(quoted_value)
+    } else if matches!(options.quoting, QuoteStyle::None) {
+        escape_unquoted(value, options)
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+// QuoteStyle::None에서는 따옴표로 감쌀 수 없으므로 구분자와 줄바꿈을 escapechar로 이스케이프함.
+// escapechar가 없으면 그대로 쓰면 레코드가 깨지므로 에러 (Python csv의 QUOTE_NONE과 같음).
+// TSV 프리셋뿐 아니라 QuoteStyle::None을 쓰는 모든 경우에 적용되며, 예전처럼 그대로 쓰던 동작과는 호환되지 않음
+fn escape_unquoted(value: &str, options: &WriterOptions) -> Result<String, CsvError> {
+    let special = |c: char| c == options.delimiter as char || c == '\n' || c == '\r';
+    let Some(escapechar) = options.escapechar.map(|e| e as char) else {
+        if value.contains(special) {
+            return Err(format!(
+                "Need to escape {:?} with QuoteStyle::None but no escapechar is set",
+                value
+            )
+            .into());
+        }
+        return Ok(value.to_string());
+    };
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if special(c) || c == escapechar {
+            escaped.push(escapechar);
+        }
+        escaped.push(c);
+    }
+    Ok(escaped)
+}
+
+// \r\n을 먼저 처리해야 한 줄바꿈이 두 번 바뀌지 않음
+fn normalize_newlines(value: &str, newline: &str) -> String {
+    let mut normalized = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                normalized.push_str(newline);
+            }
+            '\n' => normalized.push_str(newline),
+            c => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+    Lexical,
+    // 숫자로 읽을 수 없는 값은 숫자 뒤에 사전순으로 정렬
+    Numeric,
+}
+
+// writerow로 받은 행을 모두 메모리에 모아 두었다가 finish()에서 key 기준으로 정렬해 기록함.
+// 따라서 스트리밍으로 동작하지 않고 메모리 사용량은 데이터 크기에 비례함
+#[derive(Debug)]
+pub struct SortingWriter<W>
+where
+    W: Write,
+{
+    pub writer: DictWriter<W>,
+    pub key: String,
+    pub order: SortOrder,
+    pub rows: Vec<HashMap<String, String>>,
+}
+
+impl<W> SortingWriter<W>
+where
+    W: Write,
+{
+    pub fn new(writer: DictWriter<W>, key: &str, order: SortOrder) -> Self {
+        Self {
+            writer,
+            key: key.to_string(),
+            order,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn writerow<V>(&mut self, row: HashMap<String, V>) -> Result<(), Box<dyn Error>>
+    where
+        V: ToString + Clone,
+    {
+        if !row.contains_key(&self.key) {
+            return Err(format!("Row is missing the sort key {:?}", self.key).into());
+        }
+        self.rows
+            .push(row.into_iter().map(|(k, v)| (k, v.to_string())).collect());
+        Ok(())
+    }
+
+    // 헤더와 정렬된 행을 기록하고, 기록한 바이트 수를 반환
+    pub fn finish(mut self) -> Result<usize, Box<dyn Error>> {
+        let key = self.key.clone();
+        match self.order {
+            SortOrder::Lexical => self.rows.sort_by(|a, b| a[&key].cmp(&b[&key])),
+            SortOrder::Numeric => self.rows.sort_by(|a, b| {
+                match (a[&key].trim().parse::<f64>(), b[&key].trim().parse::<f64>()) {
+                    (Ok(x), Ok(y)) => x.total_cmp(&y),
+                    (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+                    (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+                    (Err(_), Err(_)) => a[&key].cmp(&b[&key]),
+                }
+            }),
+        }
+
+        let mut bytes_written = 0;
+        if !self.writer.infer_header {
+            bytes_written += self.writer.writeheader()?;
+        }
+        for row in std::mem::take(&mut self.rows) {
+            bytes_written += self.writer.writerow(row)?;
+        }
+        self.writer.flush()?;
+        Ok(bytes_written)
+    }
+}
+
+// FooterWriter가 마지막 행에 넣을 컬럼별 집계
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FooterAgg {
+    // 비어 있지 않은 값의 합. 숫자로 읽을 수 없는 값이 있으면 writerow에서 에러
+    Sum,
+    // 비어 있지 않은 값의 개수
+    Count,
+}
+
+// 합계 행의 구성. label_column에는 label을, aggregates에 있는 컬럼에는 집계 결과를,
+// 나머지 컬럼에는 fill을 넣음
+#[derive(Debug, Clone, Default)]
+pub struct FooterSpec {
+    pub label_column: Option<String>,
+    pub label: String,
+    pub aggregates: HashMap<String, FooterAgg>,
+    pub fill: String,
+}
+
+impl<W> DictWriter<W>
+where
+    W: Write,
+{
+    // 기록하는 행들을 집계해 finish()에서 합계 행을 덧붙이는 writer로 감쌈
+    pub fn with_footer(self, footer_spec: FooterSpec) -> FooterWriter<W> {
+        FooterWriter {
+            writer: self,
+            footer_spec,
+            sums: HashMap::new(),
+            decimals: HashMap::new(),
+            counts: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FooterWriter<W>
+where
+    W: Write,
+{
+    pub writer: DictWriter<W>,
+    pub footer_spec: FooterSpec,
+    sums: HashMap<String, f64>,
+    // Sum 컬럼별로 입력에서 본 가장 긴 소수 자릿수
+    decimals: HashMap<String, usize>,
+    counts: HashMap<String, u64>,
+}
+
+impl<W> FooterWriter<W>
+where
+    W: Write,
+{
+    pub fn writerow<V>(&mut self, row: HashMap<String, V>) -> Result<usize, Box<dyn Error>>
+    where
+        V: ToString + Clone,
+    {
+        let row: HashMap<String, String> =
+            row.into_iter().map(|(k, v)| (k, v.to_string())).collect();
+        // 모든 컬럼을 먼저 파싱하고 행을 기록한 뒤에 반영해, 실패한 행이 일부만 집계되지 않도록 함
+        let mut parsed = Vec::new();
+        for (column, agg) in &self.footer_spec.aggregates {
+            let Some(value) = row.get(column).map(|v| v.trim()) else {
+                continue;
+            };
+            if value.is_empty() {
+                continue;
+            }
+            let number = match agg {
+                FooterAgg::Sum => Some((
+                    value
+                        .parse::<f64>()
+                        .map_err(|_| format!("Cannot sum {:?} of column {:?}", value, column))?,
+                    decimals(value),
+                )),
+                FooterAgg::Count => None,
+            };
+            parsed.push((column.clone(), number));
+        }
+        let bytes_written = self.writer.writerow(row)?;
+        for (column, number) in parsed {
+            if let Some((number, decimals)) = number {
+                *self.sums.entry(column.clone()).or_default() += number;
+                let max = self.decimals.entry(column.clone()).or_default();
+                *max = (*max).max(decimals);
+            }
+            *self.counts.entry(column).or_default() += 1;
+        }
+        Ok(bytes_written)
+    }
+
+    // 합계 행을 기록하고 그 바이트 수를 반환
+    pub fn finish(mut self) -> Result<usize, Box<dyn Error>> {
+        let spec = &self.footer_spec;
+        let mut footer = HashMap::new();
+        for fieldname in &self.writer.fieldnames {
+            let value = if spec.label_column.as_ref() == Some(fieldname) {
+                spec.label.clone()
+            } else {
+                match spec.aggregates.get(fieldname) {
+                    // 입력에 나온 가장 긴 소수 자릿수로 맞춰 0.1 + 0.2가 0.3으로 기록되도록 함
+                    Some(FooterAgg::Sum) => format!(
+                        "{:.*}",
+                        self.decimals.get(fieldname).copied().unwrap_or_default(),
+                        self.sums.get(fieldname).copied().unwrap_or_default()
+                    ),
+                    Some(FooterAgg::Count) => self
+                        .counts
+                        .get(fieldname)
+                        .copied()
+                        .unwrap_or_default()
+                        .to_string(),
+                    None => spec.fill.clone(),
+                }
+            };
+            footer.insert(fieldname.clone(), value);
+        }
+        let bytes_written = self.writer.writerow(footer)?;
+        self.writer.flush()?;
+        Ok(bytes_written)
+    }
+}
+
+// 숫자 문자열의 소수점 아래 자릿수. 지수 표기는 가수의 소수 자릿수에서 지수를 뺀 값
+// (1e-3 → 3, 2.5e-1 → 2, 1.5e2 → 0)
+pub(crate) fn decimals(value: &str) -> usize {
+    let (mantissa, exponent) = match value.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().unwrap_or(0)),
+        None => (value, 0),
+    };
+    let frac = mantissa.split_once('.').map_or(0, |(_, frac)| frac.len()) as i64;
+    (frac - exponent).max(0) as usize
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,7 +706,7 @@ mod tests {
     fn test_dict_writer_escapechar() -> Result<(), Box<dyn Error>> {
         let mut buffer = Cursor::new(Vec::new());
         let fieldnames = vec!["header1".to_string(), "header2".to_string()];
-        let mut options = WriterOptions {
+        let options = WriterOptions {
             escapechar: Some(b'\\'),
             doublequote: false, // doublequote를 false로 설정
             quoting: QuoteStyle::All,
@@ -287,8 +733,10 @@ mod tests {
     fn test_dict_writer_lineterminator() -> Result<(), Box<dyn Error>> {
         let mut buffer = Cursor::new(Vec::new());
         let fieldnames = vec!["header1".to_string(), "header2".to_string()];
-        let mut options = WriterOptions::default();
-        options.lineterminator = "\n".to_string();
+        let options = WriterOptions {
+            lineterminator: "\n".to_string(),
+            ..Default::default()
+        };
         {
             let mut writer = DictWriter::new(&mut buffer, fieldnames.clone(), options);
             writer.writeheader()?;
@@ -306,9 +754,11 @@ mod tests {
     fn test_dict_writer_doublequote() -> Result<(), Box<dyn Error>> {
         let mut buffer = Cursor::new(Vec::new());
         let fieldnames = vec!["header1".to_string(), "header2".to_string()];
-        let mut options = WriterOptions::default();
-        options.doublequote = true;
-        options.quoting = QuoteStyle::All;
+        let options = WriterOptions {
+            doublequote: true,
+            quoting: QuoteStyle::All,
+            ..Default::default()
+        };
         {
             let mut writer = DictWriter::new(&mut buffer, fieldnames.clone(), options);
             writer.writeheader()?;
@@ -329,8 +779,10 @@ mod tests {
     fn test_dict_writer_no_quote() -> Result<(), Box<dyn Error>> {
         let mut buffer = Cursor::new(Vec::new());
         let fieldnames = vec!["header1".to_string(), "header2".to_string()];
-        let mut options = WriterOptions::default();
-        options.quoting = QuoteStyle::None;
+        let options = WriterOptions {
+            quoting: QuoteStyle::None,
+            ..Default::default()
+        };
         {
             let mut writer = DictWriter::new(&mut buffer, fieldnames.clone(), options);
             writer.writeheader()?;
@@ -343,4 +795,520 @@ mod tests {
         assert_eq!(contents, "header1,header2\r\nvalue1,value2\r\n");
         Ok(())
     }
+
+    #[test]
+    fn test_dict_writer_infer_header() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let mut writer = DictWriter::new_infer_header(&mut buffer, WriterOptions::default());
+            let mut row1 = HashMap::new();
+            row1.insert("header1".to_string(), "value1".to_string());
+            row1.insert("header2".to_string(), "value2".to_string());
+            writer.writerow(row1)?;
+
+            let mut row2 = HashMap::new();
+            row2.insert("header1".to_string(), "value3".to_string());
+            row2.insert("header2".to_string(), "value4".to_string());
+            writer.writerow(row2)?;
+        }
+        let contents = String::from_utf8(buffer.into_inner())?;
+        assert_eq!(
+            contents,
+            "header1,header2\r\nvalue1,value2\r\nvalue3,value4\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_infer_header_empty_first_row() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let mut writer = DictWriter::new_infer_header(&mut buffer, WriterOptions::default());
+            assert!(writer.writerow(HashMap::<String, String>::new()).is_err());
+            let mut row = HashMap::new();
+            row.insert("a".to_string(), "1".to_string());
+            writer.writerow(row)?;
+        }
+        assert_eq!(String::from_utf8(buffer.into_inner())?, "a\r\n1\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_infer_header_mismatch() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let mut writer = DictWriter::new_infer_header(&mut buffer, WriterOptions::default());
+        let mut row1 = HashMap::new();
+        row1.insert("header1".to_string(), "value1".to_string());
+        writer.writerow(row1)?;
+
+        let mut row2 = HashMap::new();
+        row2.insert("header2".to_string(), "value2".to_string());
+        assert!(writer.writerow(row2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_column_formats() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames = vec!["price".to_string(), "qty".to_string()];
+        let mut column_formats = HashMap::new();
+        column_formats.insert("price".to_string(), ColumnFormat::Float { decimals: 2 });
+        column_formats.insert("qty".to_string(), ColumnFormat::Integer);
+        let options = WriterOptions {
+            column_formats,
+            ..Default::default()
+        };
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, options);
+            writer.writeheader()?;
+            let mut row1 = HashMap::new();
+            row1.insert("price".to_string(), "3.5".to_string());
+            row1.insert("qty".to_string(), "2.0".to_string());
+            writer.writerow(row1)?;
+            let mut row2 = HashMap::new();
+            row2.insert("price".to_string(), "n/a".to_string());
+            row2.insert("qty".to_string(), "".to_string());
+            writer.writerow(row2)?;
+        }
+        let contents = String::from_utf8(buffer.into_inner())?;
+        assert_eq!(contents, "price,qty\r\n3.50,2\r\nn/a,\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_column_formats_strict() {
+        let mut buffer = Cursor::new(Vec::new());
+        let mut column_formats = HashMap::new();
+        column_formats.insert("price".to_string(), ColumnFormat::Float { decimals: 2 });
+        let options = WriterOptions {
+            column_formats,
+            strict: true,
+            ..Default::default()
+        };
+        let mut writer = DictWriter::new(&mut buffer, vec!["price".to_string()], options);
+        let mut row1 = HashMap::new();
+        row1.insert("price".to_string(), "n/a".to_string());
+        assert!(writer.writerow(row1).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_dict_writer_column_formats_date() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let mut column_formats = HashMap::new();
+        column_formats.insert(
+            "date".to_string(),
+            ColumnFormat::Date {
+                fmt: "%d/%m/%Y".to_string(),
+            },
+        );
+        let options = WriterOptions {
+            column_formats,
+            ..Default::default()
+        };
+        {
+            let mut writer = DictWriter::new(&mut buffer, vec!["date".to_string()], options);
+            let mut row1 = HashMap::new();
+            row1.insert("date".to_string(), "05/03/2024".to_string());
+            writer.writerow(row1)?;
+            let mut row2 = HashMap::new();
+            row2.insert("date".to_string(), "2024-03-06".to_string());
+            writer.writerow(row2)?;
+        }
+        let contents = String::from_utf8(buffer.into_inner())?;
+        assert_eq!(contents, "2024-03-05\r\n2024-03-06\r\n");
+        Ok(())
+    }
+
+    fn write_single_row(options: WriterOptions, values: &[&str]) -> Result<String, Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames: Vec<String> = (0..values.len()).map(|i| format!("h{}", i)).collect();
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames.clone(), options);
+            let row: HashMap<String, String> = fieldnames
+                .into_iter()
+                .zip(values.iter().map(|v| v.to_string()))
+                .collect();
+            writer.writerow(row)?;
+        }
+        Ok(String::from_utf8(buffer.into_inner())?)
+    }
+
+    #[test]
+    fn test_dict_writer_quote_minimal_triggers() -> Result<(), Box<dyn Error>> {
+        let options = WriterOptions {
+            quoting: QuoteStyle::Minimal,
+            ..Default::default()
+        };
+        let contents = write_single_row(options, &["a,b", "a\"b", "a\nb", "a\rb", "a b"])?;
+        assert_eq!(contents, "\"a,b\",\"a\"\"b\",\"a\nb\",\"a\rb\",a b\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_normalize_field_newlines() -> Result<(), Box<dyn Error>> {
+        let options = WriterOptions {
+            normalize_field_newlines: Some("\r\n".to_string()),
+            lineterminator: "\n".to_string(),
+            ..Default::default()
+        };
+        let contents = write_single_row(options, &["a\nb", "c\r\nd", "e\rf"])?;
+        assert_eq!(contents, "\"a\r\nb\",\"c\r\nd\",\"e\r\nf\"\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_quote_empty() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let options = WriterOptions {
+            quote_empty: true,
+            ..Default::default()
+        };
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, options);
+            let mut row = HashMap::new();
+            row.insert("a".to_string(), "1".to_string());
+            row.insert("b".to_string(), "".to_string());
+            writer.writerow(row)?;
+        }
+        assert_eq!(String::from_utf8(buffer.into_inner())?, "1,\"\",\r\n");
+
+        let options = WriterOptions {
+            restval: "NA".to_string(),
+            ..Default::default()
+        };
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let mut writer =
+                DictWriter::new(&mut buffer, vec!["a".to_string(), "b".to_string()], options);
+            let mut row = HashMap::new();
+            row.insert("a".to_string(), "");
+            writer.writerow(row)?;
+        }
+        assert_eq!(String::from_utf8(buffer.into_inner())?, ",NA\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_writeheader_if_needed() -> Result<(), Box<dyn Error>> {
+        let fieldnames = vec!["a".to_string(), "b".to_string()];
+        let mut row = HashMap::new();
+        row.insert("a".to_string(), "3".to_string());
+        row.insert("b".to_string(), "4".to_string());
+
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let mut writer =
+                DictWriter::new(&mut buffer, fieldnames.clone(), WriterOptions::default());
+            assert!(writer.writeheader_if_needed()? > 0);
+            writer.writerow(row.clone())?;
+        }
+        assert_eq!(String::from_utf8(buffer.into_inner())?, "a,b\r\n3,4\r\n");
+
+        let mut buffer = Cursor::new(b"a,b\r\n1,2\r\n".to_vec());
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, WriterOptions::default());
+            assert_eq!(writer.writeheader_if_needed()?, 0);
+            writer.writerow(row)?;
+        }
+        assert_eq!(
+            String::from_utf8(buffer.into_inner())?,
+            "a,b\r\n1,2\r\n3,4\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_quote_necessary_triggers() -> Result<(), Box<dyn Error>> {
+        let options = WriterOptions {
+            quoting: QuoteStyle::Necessary,
+            ..Default::default()
+        };
+        let contents = write_single_row(options, &["a,b", "a\"b", "a\nb", "a\rb", "a b"])?;
+        assert_eq!(contents, "\"a,b\",\"a\"\"b\",a\nb,a\rb,a b\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorting_writer() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames = vec!["id".to_string(), "name".to_string()];
+        {
+            let writer = DictWriter::new(&mut buffer, fieldnames, WriterOptions::default());
+            let mut writer = SortingWriter::new(writer, "id", SortOrder::Numeric);
+            for (id, name) in [("10", "c"), ("2", "a"), ("x", "d"), ("3", "b")] {
+                let mut row = HashMap::new();
+                row.insert("id".to_string(), id.to_string());
+                row.insert("name".to_string(), name.to_string());
+                writer.writerow(row)?;
+            }
+            writer.finish()?;
+        }
+        let contents = String::from_utf8(buffer.into_inner())?;
+        assert_eq!(contents, "id,name\r\n2,a\r\n3,b\r\n10,c\r\nx,d\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorting_writer_lexical() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let writer = DictWriter::new(
+                &mut buffer,
+                vec!["id".to_string()],
+                WriterOptions::default(),
+            );
+            let mut writer = SortingWriter::new(writer, "id", SortOrder::Lexical);
+            for id in ["10", "2", "3"] {
+                let mut row = HashMap::new();
+                row.insert("id".to_string(), id);
+                writer.writerow(row)?;
+            }
+            writer.finish()?;
+        }
+        let contents = String::from_utf8(buffer.into_inner())?;
+        assert_eq!(contents, "id\r\n10\r\n2\r\n3\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_tsv_round_trip() -> Result<(), Box<dyn Error>> {
+        use crate::reader::{DictReader, ReaderOptions};
+
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames = vec!["name".to_string(), "quote".to_string()];
+        let mut rows = Vec::new();
+        for (name, quote) in [("alice", "say \"hi\", ok"), ("bob", "plain")] {
+            let mut row = HashMap::new();
+            row.insert("name".to_string(), name.to_string());
+            row.insert("quote".to_string(), quote.to_string());
+            rows.push(row);
+        }
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, WriterOptions::tsv());
+            writer.writeheader()?;
+            writer.writerows(rows.clone())?;
+        }
+        let contents = String::from_utf8(buffer.get_ref().clone())?;
+        assert_eq!(contents, "name\tquote\nalice\tsay \"hi\", ok\nbob\tplain\n");
+
+        buffer.set_position(0);
+        let mut reader = DictReader::new(buffer, ReaderOptions::tsv())?;
+        assert_eq!(reader.read_records()?, rows);
+
+        // 필드 안의 탭과 줄바꿈은 escapechar 없이는 쓸 수 없음
+        let mut row = HashMap::new();
+        row.insert("name".to_string(), "carol".to_string());
+        row.insert("quote".to_string(), "x\ty\nz\\".to_string());
+        let fieldnames = vec!["name".to_string(), "quote".to_string()];
+        let mut writer = DictWriter::new(Vec::new(), fieldnames.clone(), WriterOptions::tsv());
+        assert!(writer.writerow(row.clone()).is_err());
+
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let options = WriterOptions {
+                escapechar: Some(b'\\'),
+                ..WriterOptions::tsv()
+            };
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, options);
+            writer.writeheader()?;
+            writer.writerow(row.clone())?;
+        }
+        let contents = String::from_utf8(buffer.get_ref().clone())?;
+        assert_eq!(contents, "name\tquote\ncarol\tx\\\ty\\\nz\\\\\n");
+
+        buffer.set_position(0);
+        let options = ReaderOptions {
+            escapechar: Some(b'\\'),
+            ..ReaderOptions::tsv()
+        };
+        let mut reader = DictReader::new(buffer, options)?;
+        assert_eq!(reader.read_records()?, vec![row]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_writer_infer_header_sorted() -> Result<(), Box<dyn Error>> {
+        for header_order in [HeaderOrder::default(), HeaderOrder::FirstSeenSorted] {
+            let mut buffer = Cursor::new(Vec::new());
+            {
+                let options = WriterOptions {
+                    header_order,
+                    ..Default::default()
+                };
+                let mut writer = DictWriter::new_infer_header(&mut buffer, options);
+                let mut row1 = HashMap::new();
+                for key in ["b", "a", "c"] {
+                    row1.insert(key.to_string(), format!("value_{}", key));
+                }
+                writer.writerow(row1)?;
+            }
+            let contents = String::from_utf8(buffer.into_inner())?;
+            assert_eq!(contents, "a,b,c\r\nvalue_a,value_b,value_c\r\n");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_escape_field_round_trip() -> Result<(), Box<dyn Error>> {
+        use crate::reader::{unescape_field, ReaderOptions};
+
+        let value = "say \"hi\", ok";
+        let escaped = escape_field(value, &WriterOptions::default())?;
+        assert_eq!(escaped, "\"say \"\"hi\"\", ok\"");
+        assert_eq!(unescape_field(&escaped, &ReaderOptions::default())?, value);
+
+        let options = WriterOptions {
+            delimiter: b';',
+            doublequote: false,
+            escapechar: Some(b'\\'),
+            ..Default::default()
+        };
+        let escaped = escape_field(value, &options)?;
+        let reader_options = ReaderOptions {
+            delimiter: b';',
+            escapechar: Some(b'\\'),
+            ..Default::default()
+        };
+        assert_eq!(unescape_field(&escaped, &reader_options)?, value);
+
+        let padded = "  padded  ";
+        for quoting in [QuoteStyle::Minimal, QuoteStyle::All] {
+            let options = WriterOptions {
+                quoting,
+                ..Default::default()
+            };
+            let escaped = escape_field(padded, &options)?;
+            assert_eq!(unescape_field(&escaped, &ReaderOptions::default())?, padded);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_dialect_presets() -> Result<(), Box<dyn Error>> {
+        let mut row = HashMap::new();
+        row.insert("header1".to_string(), "value1".to_string());
+        row.insert("header2".to_string(), "value 2".to_string());
+        let fieldnames = vec!["header1".to_string(), "header2".to_string()];
+        let mut outputs = Vec::new();
+        for options in [
+            WriterOptions::unix(),
+            WriterOptions::excel(),
+            WriterOptions::excel_tab(),
+        ] {
+            let mut buffer = Cursor::new(Vec::new());
+            {
+                let mut writer = DictWriter::new(&mut buffer, fieldnames.clone(), options);
+                writer.writeheader()?;
+                writer.writerow(row.clone())?;
+            }
+            outputs.push(String::from_utf8(buffer.into_inner())?);
+        }
+        assert_eq!(
+            outputs[0],
+            "\"header1\",\"header2\"\n\"value1\",\"value 2\"\n"
+        );
+        assert_eq!(outputs[1], "header1,header2\r\nvalue1,value 2\r\n");
+        assert_eq!(outputs[2], "header1\theader2\r\nvalue1\tvalue 2\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_footer_writer() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames = vec![
+            "item".to_string(),
+            "qty".to_string(),
+            "price".to_string(),
+            "note".to_string(),
+        ];
+        let spec = FooterSpec {
+            label_column: Some("item".to_string()),
+            label: "Total".to_string(),
+            aggregates: HashMap::from([
+                ("qty".to_string(), FooterAgg::Count),
+                ("price".to_string(), FooterAgg::Sum),
+            ]),
+            fill: "-".to_string(),
+        };
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, WriterOptions::default())
+                .with_footer(spec);
+            writer.writer.writeheader()?;
+            for (item, qty, price) in [("a", "1", "1.5"), ("b", "", "2"), ("c", "3", "10")] {
+                let row = HashMap::from([
+                    ("item".to_string(), item),
+                    ("qty".to_string(), qty),
+                    ("price".to_string(), price),
+                ]);
+                writer.writerow(row)?;
+            }
+            writer.finish()?;
+        }
+        assert_eq!(
+            String::from_utf8(buffer.into_inner())?,
+            "item,qty,price,note\r\na,1,1.5,\r\nb,,2,\r\nc,3,10,\r\nTotal,2,13.5,-\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_footer_writer_decimals_and_rejected_rows() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames = vec!["label".to_string(), "a".to_string(), "b".to_string()];
+        let spec = FooterSpec {
+            label_column: Some("label".to_string()),
+            label: "Total".to_string(),
+            aggregates: HashMap::from([
+                ("a".to_string(), FooterAgg::Sum),
+                ("b".to_string(), FooterAgg::Sum),
+            ]),
+            ..Default::default()
+        };
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, WriterOptions::default())
+                .with_footer(spec);
+            for (a, b) in [("0.1", "1"), ("0.2", "2")] {
+                writer.writerow(HashMap::from([("a".to_string(), a), ("b".to_string(), b)]))?;
+            }
+            // 한 컬럼이라도 파싱에 실패하면 다른 컬럼도 집계에 들어가지 않음
+            let bad = HashMap::from([("a".to_string(), "5"), ("b".to_string(), "x")]);
+            assert!(writer.writerow(bad.clone()).is_err());
+            let bad = HashMap::from([("a".to_string(), "x"), ("b".to_string(), "5")]);
+            assert!(writer.writerow(bad).is_err());
+            writer.finish()?;
+        }
+        assert_eq!(
+            String::from_utf8(buffer.into_inner())?,
+            ",0.1,1\r\n,0.2,2\r\nTotal,0.3,3\r\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_footer_writer_exponent_decimals() -> Result<(), Box<dyn Error>> {
+        let mut buffer = Cursor::new(Vec::new());
+        let fieldnames = vec!["a".to_string(), "b".to_string()];
+        let spec = FooterSpec {
+            aggregates: HashMap::from([
+                ("a".to_string(), FooterAgg::Sum),
+                ("b".to_string(), FooterAgg::Sum),
+            ]),
+            ..Default::default()
+        };
+        {
+            let mut writer = DictWriter::new(&mut buffer, fieldnames, WriterOptions::default())
+                .with_footer(spec);
+            for (a, b) in [("1e-3", "1E-3"), ("2e-3", "2.5e-1"), ("1.5", "1.5e2")] {
+                writer.writerow(HashMap::from([("a".to_string(), a), ("b".to_string(), b)]))?;
+            }
+            writer.finish()?;
+        }
+        let out = String::from_utf8(buffer.into_inner())?;
+        assert_eq!(out.lines().last(), Some("1.503,150.251"));
+        assert_eq!(decimals("1.5e2"), 0);
+        assert_eq!(decimals("-2.50"), 2);
+        Ok(())
+    }
 }